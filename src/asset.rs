@@ -0,0 +1,79 @@
+use bevy_asset::prelude::*;
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{rebuild, Viewable};
+
+/// Rebuilds the [`View`](crate::View) of every [`Viewable`] of [`Kind`] `T` if any [`Asset`] `A` was modified.
+pub fn rebuild_views_on_asset<T: Kind, A: Asset>(
+    mut events: EventReader<AssetEvent<A>>,
+    viewables: Query<InstanceRef<Viewable<T>>>,
+    mut commands: Commands,
+) {
+    let modified = events
+        .read()
+        .any(|event| matches!(event, AssetEvent::Modified { .. }));
+    if !modified {
+        return;
+    }
+    for viewable in viewables.iter() {
+        rebuild(viewable, &mut commands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+    use bevy::reflect::TypePath;
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Asset)]
+    struct Palette(u32);
+
+    // `derive(TypePath)` resolves to the optional `bevy_reflect` dependency, which `asset` does not enable.
+    impl TypePath for Palette {
+        fn type_path() -> &'static str {
+            "moonshine_view::asset::tests::Palette"
+        }
+
+        fn short_type_path() -> &'static str {
+            "Palette"
+        }
+    }
+
+    #[test]
+    fn modified_asset_rebuilds_views() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Palette>()
+            .add_view_rebuild_on_asset::<Node, Palette>();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<Palette>>()
+            .add(Palette(0));
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap();
+        app.update();
+        assert_eq!(app.world().view::<Node>(node), Some(view));
+
+        app.world_mut()
+            .resource_mut::<Assets<Palette>>()
+            .get_mut(&handle)
+            .unwrap()
+            .0 = 1;
+        app.update();
+        app.update();
+        let rebuilt = app.world().view::<Node>(node).unwrap();
+        assert_ne!(rebuilt, view);
+        app.update();
+        assert_eq!(app.world().view::<Node>(node), Some(rebuilt));
+    }
+}
@@ -0,0 +1,186 @@
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::Instant;
+
+use moonshine_core::prelude::*;
+
+/// A [`Resource`] which stores the budget set using [`add_view_time_budget`](crate::RegisterView::add_view_time_budget).
+#[derive(Resource)]
+pub struct ViewTimeBudget<T: Kind> {
+    budget: Duration,
+    clock: Box<dyn Fn() -> Duration + Send + Sync>,
+    cost: Mutex<ViewBuildCost>,
+    marker: PhantomData<T>,
+}
+
+#[derive(Default)]
+struct ViewBuildCost {
+    /// Time spent building views since the last spawn.
+    spent: Duration,
+    /// Number of views spawned by the last spawn.
+    spawned: u32,
+    /// Estimated time spent building a single view.
+    estimate: Option<Duration>,
+    /// Time at which the current view build started, if any.
+    started: Option<Duration>,
+}
+
+impl<T: Kind> ViewTimeBudget<T> {
+    pub fn new(budget: Duration) -> Self {
+        let origin = Instant::now();
+        Self::with_clock(budget, move || origin.elapsed())
+    }
+
+    /// Creates a new budget which measures time using the given clock.
+    ///
+    /// The clock returns the time elapsed since any fixed origin.
+    pub fn with_clock(
+        budget: Duration,
+        clock: impl Fn() -> Duration + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            budget,
+            clock: Box::new(clock),
+            cost: Mutex::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of views which may be spawned this frame, which is always at least one.
+    ///
+    /// This also updates the estimated cost of a single view based on the views spawned during the last frame.
+    /// Until any time has been measured, only one view is spawned per frame.
+    pub fn begin_spawn(&self) -> usize {
+        let mut cost = self.cost.lock().unwrap();
+        if cost.spawned > 0 && !cost.spent.is_zero() {
+            let estimate = cost.spent / cost.spawned;
+            cost.estimate = Some(estimate.max(Duration::from_nanos(1)));
+        }
+        cost.spent = Duration::ZERO;
+        cost.spawned = 0;
+        match cost.estimate {
+            Some(estimate) => (self.budget.as_nanos() / estimate.as_nanos()).max(1) as usize,
+            None => 1,
+        }
+    }
+
+    /// Records the number of views spawned this frame.
+    pub fn end_spawn(&self, spawned: u32) {
+        self.cost.lock().unwrap().spawned = spawned;
+    }
+
+    /// Records the time spent building a single view.
+    pub fn add_spent(&self, elapsed: Duration) {
+        self.cost.lock().unwrap().spent += elapsed;
+    }
+
+    /// Returns the current time of the clock used by this budget.
+    pub fn now(&self) -> Duration {
+        (self.clock)()
+    }
+
+    /// Starts measuring the time spent building a single view.
+    pub fn begin_build(&self) {
+        self.cost.lock().unwrap().started = Some(self.now());
+    }
+
+    /// Stops measuring the time spent building a single view, and records it.
+    pub fn end_build(&self) {
+        let now = self.now();
+        let mut cost = self.cost.lock().unwrap();
+        if let Some(started) = cost.started.take() {
+            cost.spent += now.saturating_sub(started);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    const COST: Duration = Duration::from_millis(1);
+
+    /// A clock which only advances when views of [`Costly`] are built.
+    #[derive(Resource, Clone, Default)]
+    struct Clock(Arc<AtomicU64>);
+
+    impl Clock {
+        fn now(&self) -> Duration {
+            Duration::from_nanos(self.0.load(Ordering::Relaxed))
+        }
+
+        fn advance(&self, duration: Duration) {
+            self.0
+                .fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        }
+    }
+
+    #[derive(Component)]
+    struct Costly;
+
+    impl BuildView for Costly {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            // The cost is only incurred when the commands are applied.
+            view.commands()
+                .queue(|world: &mut World| world.resource::<Clock>().advance(COST));
+        }
+    }
+
+    #[test]
+    fn views_per_frame_follow_estimate() {
+        let budget = ViewTimeBudget::<Node>::new(Duration::from_millis(10));
+        assert_eq!(budget.begin_spawn(), 1);
+        budget.add_spent(Duration::from_millis(2));
+        budget.end_spawn(1);
+        assert_eq!(budget.begin_spawn(), 5);
+        budget.add_spent(Duration::from_millis(40));
+        budget.end_spawn(2);
+        assert_eq!(budget.begin_spawn(), 1);
+    }
+
+    #[test]
+    fn zero_cost_is_not_unlimited() {
+        let budget = ViewTimeBudget::<Node>::new(Duration::from_millis(10));
+        assert_eq!(budget.begin_spawn(), 1);
+        budget.end_spawn(1);
+        assert_eq!(budget.begin_spawn(), 1);
+        budget.add_spent(Duration::from_millis(2));
+        budget.end_spawn(1);
+        assert_eq!(budget.begin_spawn(), 5);
+        budget.end_spawn(5);
+        assert_eq!(budget.begin_spawn(), 5);
+    }
+
+    #[test]
+    fn builds_are_spread_across_frames() {
+        let clock = Clock::default();
+        let mut app = ViewTestApp::with_viewable::<Costly>();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(clock.clone())
+            .insert_resource(ViewTimeBudget::<Costly>::with_clock(COST * 3, {
+                let clock = clock.clone();
+                move || clock.now()
+            }));
+        for _ in 0..10 {
+            app.world_mut().spawn(Costly);
+        }
+        let mut counts = Vec::new();
+        for _ in 0..5 {
+            app.update();
+            counts.push(app.world().view_count::<Costly>());
+        }
+        assert_eq!(counts, [1, 4, 7, 10, 10]);
+        assert_eq!(clock.now(), COST * 10);
+    }
+}
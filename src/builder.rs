@@ -0,0 +1,249 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{BuildView, RegisterView};
+
+/// A builder used to configure the views of a single [`Kind`] `T`.
+///
+/// Each method is equivalent to the [`RegisterView`] method of the same name, prefixed with `add_view_`.
+/// The configuration is accumulated and only applied to the [`App`], in order, when
+/// [`finish`](ViewKindBuilder::finish) is called. A builder which is dropped without being finished has no effect.
+///
+/// See [`RegisterView::view_kind`] for details.
+#[must_use = "the configuration is only applied when `finish` is called"]
+pub struct ViewKindBuilder<'a, T: Kind> {
+    app: &'a mut App,
+    config: Vec<ViewKindConfig>,
+    marker: PhantomData<T>,
+}
+
+type ViewKindConfig = Box<dyn FnOnce(&mut App)>;
+
+impl<'a, T: Kind> ViewKindBuilder<'a, T> {
+    pub(crate) fn new(app: &'a mut App) -> Self {
+        Self {
+            app,
+            config: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    fn with(mut self, config: impl FnOnce(&mut App) + 'static) -> Self {
+        self.config.push(Box::new(config));
+        self
+    }
+
+    /// See [`RegisterView::add_view`].
+    pub fn view<V: BuildView<T>>(self) -> Self {
+        self.with(move |app| {
+            app.add_view::<T, V>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_fallback`].
+    pub fn fallback<F: BuildView<T>>(self) -> Self {
+        self.with(move |app| {
+            app.add_view_fallback::<T, F>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_priority`].
+    pub fn priority(self, priority: i32) -> Self {
+        self.with(move |app| {
+            app.add_view_priority::<T>(priority);
+        })
+    }
+
+    /// See [`RegisterView::add_view_hierarchy`].
+    pub fn hierarchy(self) -> Self {
+        self.with(move |app| {
+            app.add_view_hierarchy::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_root`].
+    pub fn root(self, root: Entity) -> Self {
+        self.with(move |app| {
+            app.add_view_root::<T>(root);
+        })
+    }
+
+    /// See [`RegisterView::add_view_offset_transform`].
+    #[cfg(feature = "transform")]
+    pub fn offset_transform(self, offset: bevy_transform::prelude::Transform) -> Self {
+        self.with(move |app| {
+            app.add_view_offset_transform::<T>(offset);
+        })
+    }
+
+    /// See [`RegisterView::add_view_synced_transform`].
+    #[cfg(feature = "transform")]
+    pub fn synced_transform(self) -> Self {
+        self.with(move |app| {
+            app.add_view_synced_transform::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_interpolated_transform`].
+    #[cfg(feature = "transform")]
+    pub fn interpolated_transform(self, snap: f32) -> Self {
+        self.with(move |app| {
+            app.add_view_interpolated_transform::<T>(snap);
+        })
+    }
+
+    /// See [`RegisterView::add_view_spatial`].
+    #[cfg(feature = "render")]
+    pub fn spatial(self) -> Self {
+        self.with(move |app| {
+            app.add_view_spatial::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_transitions`].
+    #[cfg(feature = "transition")]
+    pub fn transitions(self, appear: Duration, disappear: Duration) -> Self {
+        self.with(move |app| {
+            app.add_view_transitions::<T>(appear, disappear);
+        })
+    }
+
+    /// See [`RegisterView::add_view_linked`].
+    pub fn linked(self, linked: bool) -> Self {
+        self.with(move |app| {
+            app.add_view_linked::<T>(linked);
+        })
+    }
+
+    /// See [`RegisterView::add_view_debounce`].
+    pub fn debounce(self, frames: u32) -> Self {
+        self.with(move |app| {
+            app.add_view_debounce::<T>(frames);
+        })
+    }
+
+    /// See [`RegisterView::add_view_time_budget`].
+    pub fn time_budget(self, budget: Duration) -> Self {
+        self.with(move |app| {
+            app.add_view_time_budget::<T>(budget);
+        })
+    }
+
+    /// See [`RegisterView::add_view_lazy`].
+    pub fn lazy(self) -> Self {
+        self.with(move |app| {
+            app.add_view_lazy::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_inactive`].
+    pub fn inactive(self) -> Self {
+        self.with(move |app| {
+            app.add_view_inactive::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_veto`].
+    pub fn veto(self, veto: impl Fn(&World, Instance<T>) -> bool + Send + Sync + 'static) -> Self {
+        self.with(move |app| {
+            app.add_view_veto::<T>(veto);
+        })
+    }
+
+    /// See [`RegisterView::add_view_settled`].
+    pub fn settled(self) -> Self {
+        self.with(move |app| {
+            app.add_view_settled::<T>();
+        })
+    }
+
+    /// See [`RegisterView::add_view_finder`].
+    pub fn finder(
+        self,
+        finder: impl Fn(&World, Instance<T>) -> Option<Entity> + Send + Sync + 'static,
+    ) -> Self {
+        self.with(move |app| {
+            app.add_view_finder::<T>(finder);
+        })
+    }
+
+    /// See [`RegisterView::add_view_preserve`].
+    pub fn preserve<C: Component + Clone>(self) -> Self {
+        self.with(move |app| {
+            app.add_view_preserve::<T, C>();
+        })
+    }
+
+    /// Applies the configuration to the [`App`] and returns it to continue configuring it.
+    pub fn finish(self) -> &'a mut App {
+        for config in self.config {
+            config(self.app);
+        }
+        self.app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_ecs::schedule::ScheduleLabel;
+    use bevy_hierarchy::prelude::*;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    fn systems_len(app: &mut App, label: impl ScheduleLabel) -> usize {
+        app.get_schedule(label)
+            .map_or(0, |schedule| schedule.systems_len())
+    }
+
+    #[test]
+    fn chain_is_applied_on_finish() {
+        let mut expected = App::new();
+        expected
+            .add_plugins(MinimalPlugins)
+            .add_view::<Node, Node>()
+            .add_view_hierarchy::<Node>()
+            .add_view_priority::<Node>(10)
+            .add_view_linked::<Node>(false)
+            .add_view_debounce::<Node>(2)
+            .add_view_inactive::<Node>();
+
+        let mut app = ViewTestApp::new();
+        app.add_plugins(MinimalPlugins);
+        app.view_kind::<Node>()
+            .view::<Node>()
+            .hierarchy()
+            .priority(10)
+            .linked(false)
+            .debounce(2)
+            .inactive()
+            .finish();
+
+        for label in [PreUpdate.intern(), PostUpdate.intern(), Last.intern()] {
+            assert_eq!(
+                systems_len(&mut app, label),
+                systems_len(&mut expected, label)
+            );
+        }
+
+        let child = app.world_mut().spawn(Node).id();
+        let parent = app.world_mut().spawn(Node).add_child(child).id();
+        app.update();
+        assert!(!app.world().has_view::<Node>(child));
+        app.update();
+        let parent_view = app.world().view::<Node>(parent).unwrap().entity();
+        let child_view = app.world().view::<Node>(child).unwrap().entity();
+        assert_eq!(
+            app.world().get::<Parent>(child_view).map(Parent::get),
+            Some(parent_view)
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::HashSet;
+
+use moonshine_core::prelude::*;
+
+use crate::{rebuild, View, Viewable};
+
+/// An [`Event`] which is sent whenever a [`Component`] `C` of a [`View`] of [`Kind`] `T` is changed.
+///
+/// This event is only sent for components registered using [`add_view_change_event`](crate::RegisterView::add_view_change_event).
+/// Insertion of the component, such as when the view is built, is not considered a change.
+#[derive(Event)]
+pub struct ViewComponentChanged<T: Kind, C: Component> {
+    /// The view whose component was changed.
+    pub view: Instance<View<T>>,
+    marker: PhantomData<C>,
+}
+
+impl<T: Kind, C: Component> ViewComponentChanged<T, C> {
+    /// Creates a new event for the given view.
+    pub fn new(view: Instance<View<T>>) -> Self {
+        Self {
+            view,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Kind, C: Component> Clone for ViewComponentChanged<T, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Kind, C: Component> Copy for ViewComponentChanged<T, C> {}
+
+/// Sends a [`ViewComponentChanged<T, C>`] for every [`View`] of [`Kind`] `T` whose [`Component`] `C` was changed.
+#[allow(clippy::type_complexity)]
+pub fn send_view_changes<T: Kind, C: Component>(
+    views: Query<(InstanceRef<View<T>>, Ref<C>), Changed<C>>,
+    mut events: EventWriter<ViewComponentChanged<T, C>>,
+) {
+    for (view, component) in views.iter() {
+        if component.is_added() {
+            continue;
+        }
+        events.send(ViewComponentChanged::new(view.instance()));
+    }
+}
+
+/// Rebuilds the [`View`] of every [`Viewable`] of [`Kind`] `T` whose [`Component`] `C` was changed.
+#[allow(clippy::type_complexity)]
+pub fn rebuild_views_on_change<T: Kind, C: Component>(
+    viewables: Query<(InstanceRef<Viewable<T>>, Ref<C>), Changed<C>>,
+    mut commands: Commands,
+) {
+    for (viewable, component) in viewables.iter() {
+        if component.is_added() {
+            continue;
+        }
+        rebuild(viewable, &mut commands);
+    }
+}
+
+/// A [`Resource`] which stores the viewables of [`Kind`] `T` whose views should be rebuilt because [`Component`] `C`
+/// was added or removed.
+///
+/// See [`add_view_rebuild_on`](crate::RegisterView::add_view_rebuild_on) for details.
+#[derive(Resource)]
+pub struct PendingViewRebuilds<T: Kind, C: Component> {
+    entities: HashSet<Entity>,
+    marker: PhantomData<(T, C)>,
+}
+
+impl<T: Kind, C: Component> Default for PendingViewRebuilds<T, C> {
+    fn default() -> Self {
+        Self {
+            entities: HashSet::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Requests a rebuild of the [`View`] of a [`Viewable`] of [`Kind`] `T` when [`Component`] `C` is added or removed.
+pub fn request_view_rebuild<T: Kind, C: Component, E: Event>(
+    trigger: Trigger<E, C>,
+    viewables: Query<(), With<Viewable<T>>>,
+    mut pending: ResMut<PendingViewRebuilds<T, C>>,
+) {
+    let entity = trigger.entity();
+    if viewables.contains(entity) {
+        pending.entities.insert(entity);
+    }
+}
+
+/// Rebuilds the [`View`] of every [`Viewable`] of [`Kind`] `T` requested by [`request_view_rebuild`].
+pub fn rebuild_pending_views<T: Kind, C: Component>(
+    viewables: Query<InstanceRef<Viewable<T>>>,
+    mut pending: ResMut<PendingViewRebuilds<T, C>>,
+    mut commands: Commands,
+) {
+    for entity in pending.entities.drain() {
+        // Viewable may have been despawned, or its view removed, since the request.
+        if let Ok(viewable) = viewables.get(entity) {
+            rebuild(viewable, &mut commands);
+        }
+    }
+}
+
+/// A trait used to produce a comparable key from a viewable of [`Kind`] `Self`, which determines when its view
+/// should be rebuilt.
+///
+/// See [`add_view_rebuild_on_key`](crate::RegisterView::add_view_rebuild_on_key) for details.
+pub trait ViewRebuildKey: Kind {
+    /// The key which represents the state of a viewable relevant to its view.
+    type Key: PartialEq + Send + Sync + 'static;
+
+    /// Returns the key of the given viewable.
+    fn rebuild_key(world: &World, object: Object<Self>) -> Self::Key;
+}
+
+/// A [`Component`] which stores the [`ViewRebuildKey`] of the viewable of a view when it was built.
+#[derive(Component)]
+pub struct BuiltViewKey<T: ViewRebuildKey> {
+    key: T::Key,
+}
+
+/// Stores the [`ViewRebuildKey`] of the viewable of every new [`View`] of [`Kind`] `T`.
+pub fn record_view_rebuild_key<T: ViewRebuildKey>(
+    world: &World,
+    views: Query<(Entity, &View<T>), Added<View<T>>>,
+    objects: Objects<T>,
+    mut commands: Commands,
+) {
+    for (view, view_data) in views.iter() {
+        let Ok(object) = objects.get(view_data.viewable().entity()) else {
+            continue;
+        };
+        let key = T::rebuild_key(world, object);
+        commands.entity(view).insert(BuiltViewKey::<T> { key });
+    }
+}
+
+/// Rebuilds the [`View`] of every [`Viewable`] of [`Kind`] `T` whose [`Component`] `C` was changed, if its
+/// [`ViewRebuildKey`] differs from the key its view was built with.
+#[allow(clippy::type_complexity)]
+pub fn rebuild_views_on_key<T: ViewRebuildKey, C: Component>(
+    world: &World,
+    viewables: Query<(InstanceRef<Viewable<T>>, Ref<C>), Changed<C>>,
+    objects: Objects<T>,
+    keys: Query<&BuiltViewKey<T>>,
+    mut commands: Commands,
+) {
+    for (viewable, component) in viewables.iter() {
+        if component.is_added() {
+            continue;
+        }
+        let Ok(object) = objects.get(viewable.entity()) else {
+            continue;
+        };
+        let key = T::rebuild_key(world, object);
+        if keys
+            .get(viewable.view().entity())
+            .is_ok_and(|built| built.key == key)
+        {
+            continue;
+        }
+        rebuild(viewable, &mut commands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Shape;
+
+    #[derive(Component)]
+    struct Color(u8);
+
+    #[derive(Component)]
+    struct Highlight;
+
+    /// Number of views of [`Shape`] built so far.
+    #[derive(Resource, Default)]
+    struct Builds(usize);
+
+    impl BuildView for Shape {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.commands()
+                .queue(|world: &mut World| world.resource_mut::<Builds>().0 += 1);
+        }
+    }
+
+    impl ViewRebuildKey for Shape {
+        type Key = u8;
+
+        fn rebuild_key(world: &World, object: Object<Self>) -> u8 {
+            world.get::<Color>(object.entity()).unwrap().0 / 10
+        }
+    }
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Shape>();
+        app.add_plugins(MinimalPlugins).init_resource::<Builds>();
+        app
+    }
+
+    fn view_of(app: &ViewTestApp, entity: Entity) -> Entity {
+        app.world().view::<Shape>(entity).unwrap().entity()
+    }
+
+    fn builds(app: &ViewTestApp) -> usize {
+        app.world().resource::<Builds>().0
+    }
+
+    #[test]
+    fn change_event_per_changed_view() {
+        let mut app = app();
+        app.add_view_change_event::<Shape, Color>();
+        let a = app.world_mut().spawn(Shape).id();
+        let b = app.world_mut().spawn(Shape).id();
+        app.update();
+        let (view_a, view_b) = (view_of(&app, a), view_of(&app, b));
+        app.world_mut().entity_mut(view_a).insert(Color(0));
+        app.world_mut().entity_mut(view_b).insert(Color(0));
+        app.update();
+        let events = app
+            .world()
+            .resource::<Events<ViewComponentChanged<Shape, Color>>>();
+        assert_eq!(events.iter_current_update_events().count(), 0);
+
+        app.world_mut().get_mut::<Color>(view_a).unwrap().0 = 1;
+        app.update();
+        let events = app
+            .world()
+            .resource::<Events<ViewComponentChanged<Shape, Color>>>();
+        let views: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| event.view.entity())
+            .collect();
+        assert_eq!(views, [view_a]);
+    }
+
+    #[test]
+    fn squad_has_one_view_rebuilt_on_membership_change() {
+        #[derive(Component)]
+        struct Squad {
+            members: Vec<Entity>,
+        }
+
+        /// The number of members seen by each build of a [`Squad`] view.
+        #[derive(Resource, Default)]
+        struct SquadBuilds(Vec<usize>);
+
+        impl BuildView for Squad {
+            fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+                let members = world.get::<Squad>(object.entity()).unwrap().members.len();
+                view.commands().queue(move |world: &mut World| {
+                    world.resource_mut::<SquadBuilds>().0.push(members)
+                });
+            }
+        }
+
+        let mut app = ViewTestApp::with_viewable::<Squad>();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<SquadBuilds>()
+            .add_view_rebuild_on_change::<Squad, Squad>();
+        let members: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect();
+        let squad = app.world_mut().spawn(Squad { members }).id();
+        app.update();
+        assert_eq!(app.world().view_count::<Squad>(), 1);
+        assert_eq!(app.world().resource::<SquadBuilds>().0, [3]);
+        let view = app.world().view::<Squad>(squad).unwrap();
+
+        let recruit = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .get_mut::<Squad>(squad)
+            .unwrap()
+            .members
+            .push(recruit);
+        app.update();
+        app.update();
+        assert_eq!(app.world().view_count::<Squad>(), 1);
+        assert_eq!(app.world().resource::<SquadBuilds>().0, [3, 4]);
+        assert_ne!(app.world().view::<Squad>(squad), Some(view));
+        assert!(app.world().get_entity(view.entity()).is_err());
+    }
+
+    #[test]
+    fn change_rebuilds_affected_view() {
+        let mut app = app();
+        app.add_view_rebuild_on_change::<Shape, Color>();
+        let a = app.world_mut().spawn((Shape, Color(0))).id();
+        let b = app.world_mut().spawn((Shape, Color(0))).id();
+        app.update();
+        let (view_a, view_b) = (view_of(&app, a), view_of(&app, b));
+        assert_eq!(builds(&app), 2);
+
+        app.world_mut().get_mut::<Color>(a).unwrap().0 = 1;
+        app.update();
+        assert!(app.world().get_entity(view_a).is_err());
+        assert!(view_of(&app, a) != view_a);
+        assert_eq!(view_of(&app, b), view_b);
+        assert_eq!(builds(&app), 3);
+    }
+
+    #[test]
+    fn rebuild_requests_coalesce() {
+        let mut app = app();
+        app.add_view_rebuild_on::<Shape, Highlight>();
+        let shape = app.world_mut().spawn(Shape).id();
+        app.update();
+        let view = view_of(&app, shape);
+        assert_eq!(builds(&app), 1);
+
+        app.world_mut().entity_mut(shape).insert(Highlight);
+        app.update();
+        let highlighted = view_of(&app, shape);
+        assert!(highlighted != view);
+        assert_eq!(builds(&app), 2);
+
+        app.world_mut().entity_mut(shape).remove::<Highlight>();
+        app.world_mut().entity_mut(shape).insert(Highlight);
+        app.world_mut().entity_mut(shape).remove::<Highlight>();
+        app.update();
+        assert!(view_of(&app, shape) != highlighted);
+        assert_eq!(builds(&app), 3);
+
+        app.update();
+        assert_eq!(builds(&app), 3);
+    }
+
+    #[test]
+    fn key_change_rebuilds_view() {
+        let mut app = app();
+        app.add_view_rebuild_on_key::<Shape, Color>();
+        let shape = app.world_mut().spawn((Shape, Color(1))).id();
+        app.update();
+        let view = view_of(&app, shape);
+
+        app.world_mut().get_mut::<Color>(shape).unwrap().0 = 2;
+        app.update();
+        assert_eq!(view_of(&app, shape), view);
+        assert_eq!(builds(&app), 1);
+
+        app.world_mut().get_mut::<Color>(shape).unwrap().0 = 12;
+        app.update();
+        assert!(view_of(&app, shape) != view);
+        assert_eq!(builds(&app), 2);
+    }
+}
@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// A [`Resource`] which stores the number of frames set using [`add_view_debounce`](crate::RegisterView::add_view_debounce).
+#[derive(Resource)]
+pub struct ViewDebounce<T: Kind> {
+    frames: u32,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewDebounce<T> {
+    pub fn new(frames: u32) -> Self {
+        Self {
+            frames,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the given viewable entity has been pending for long enough.
+    pub fn is_ready(&self, frames: Option<&DebounceFrames<T>>) -> bool {
+        frames.map_or(0, |frames| frames.frames) >= self.frames
+    }
+}
+
+/// A [`Component`] which counts the consecutive frames a viewable entity has been waiting for its view
+/// to be spawned or despawned.
+#[derive(Component)]
+pub struct DebounceFrames<T: Kind> {
+    frames: u32,
+    marker: PhantomData<T>,
+}
+
+/// Counts the frames each viewable entity of [`Kind`] `T` has been pending.
+///
+/// An entity is pending if it is of kind `T` without a view, or if it has a view but is no longer of kind `T`.
+/// The count is reset as soon as an entity is no longer pending.
+#[allow(clippy::type_complexity)]
+pub fn debounce_views<T: Kind>(
+    mut entities: Query<
+        (Entity, Has<Viewable<T>>, Option<&mut DebounceFrames<T>>),
+        Or<(T::Filter, With<Viewable<T>>, With<DebounceFrames<T>>)>,
+    >,
+    filter: Query<(), T::Filter>,
+    mut commands: Commands,
+) {
+    for (entity, viewed, frames) in entities.iter_mut() {
+        let pending = filter.contains(entity) != viewed;
+        match (pending, frames) {
+            (true, Some(mut frames)) => frames.frames += 1,
+            (true, None) => {
+                commands.entity(entity).insert(DebounceFrames::<T> {
+                    frames: 1,
+                    marker: PhantomData,
+                });
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<DebounceFrames<T>>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins).add_view_debounce::<Node>(3);
+        app
+    }
+
+    #[test]
+    fn flicker_never_spawns_view() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Node).id();
+        for _ in 0..5 {
+            app.update();
+            app.update();
+            app.world_mut().entity_mut(entity).remove::<Node>();
+            app.update();
+            app.world_mut().entity_mut(entity).insert(Node);
+        }
+        assert!(!app.world().has_view::<Node>(entity));
+        assert_eq!(app.world().view_count::<Node>(), 0);
+    }
+
+    #[test]
+    fn view_spawned_after_frames() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Node).id();
+        app.update();
+        app.update();
+        assert!(!app.world().has_view::<Node>(entity));
+        app.update();
+        assert!(app.world().has_view::<Node>(entity));
+    }
+
+    #[test]
+    fn flicker_never_despawns_view() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Node).id();
+        for _ in 0..3 {
+            app.update();
+        }
+        let view = app.world().view::<Node>(entity).unwrap();
+        for _ in 0..5 {
+            app.world_mut().entity_mut(entity).remove::<Node>();
+            app.update();
+            app.update();
+            app.world_mut().entity_mut(entity).insert(Node);
+            app.update();
+        }
+        assert!(app.world().view::<Node>(entity) == Some(view));
+
+        app.world_mut().entity_mut(entity).remove::<Node>();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(view.entity()).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::View;
+
+/// A [`Component`] which records the tags of every decoration applied to a [`View`] using [`decorate_view`].
+///
+/// This is only available with the `debug` feature. It is intended as a development aid for setups with
+/// multiple [build systems](crate::RegisterView::add_view_build_system) or observers per kind, to find out
+/// which of them contributed what to a view.
+#[cfg(feature = "debug")]
+#[derive(Component, Default, Debug)]
+pub struct ViewDecorations {
+    tags: Vec<&'static str>,
+}
+
+#[cfg(feature = "debug")]
+impl ViewDecorations {
+    /// Iterates over the tags of all decorations applied to the view, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.tags.iter().copied()
+    }
+
+    /// Returns `true` if a decoration with the given tag was applied to the view.
+    pub fn contains(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+/// Inserts the given [`Bundle`] into the given [`View`], attributed to the given tag.
+///
+/// If the `debug` feature is enabled, the tag is recorded in the [`ViewDecorations`] of the view.
+/// Otherwise, this is equivalent to inserting the bundle directly. Typically, the tag is the name of the
+/// system or observer which decorates the view.
+///
+/// Nothing happens if the view no longer exists.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Unit;
+///
+/// #[derive(Component)]
+/// struct HealthBar;
+///
+/// fn add_health_bar(views: Query<Instance<View<Unit>>, Added<View<Unit>>>, mut commands: Commands) {
+///     for view in views.iter() {
+///         moonshine_view::decorate_view(view, "add_health_bar", HealthBar, &mut commands);
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(add_health_bar);
+/// ```
+pub fn decorate_view<T: Kind>(
+    view: Instance<View<T>>,
+    tag: &'static str,
+    bundle: impl Bundle,
+    commands: &mut Commands,
+) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut view_entity) = world.get_entity_mut(view.entity()) else {
+            return;
+        };
+        view_entity.insert(bundle);
+        #[cfg(feature = "debug")]
+        view_entity
+            .entry::<ViewDecorations>()
+            .or_default()
+            .tags
+            .push(tag);
+        #[cfg(not(feature = "debug"))]
+        let _ = tag;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Outline;
+
+    #[derive(Component)]
+    struct Label;
+
+    #[test]
+    fn decorations_accumulate() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_build_system::<Node, _>(
+                |In(view): In<Instance<View<Node>>>, mut commands: Commands| {
+                    decorate_view(view, "outline", Outline, &mut commands);
+                },
+            )
+            .add_view_build_system::<Node, _>(
+                |In(view): In<Instance<View<Node>>>, mut commands: Commands| {
+                    decorate_view(view, "label", Label, &mut commands);
+                },
+            );
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert!(app.world().get::<Outline>(view).is_some());
+        assert!(app.world().get::<Label>(view).is_some());
+        #[cfg(feature = "debug")]
+        {
+            let mut tags: Vec<_> = app
+                .world()
+                .get::<ViewDecorations>(view)
+                .unwrap()
+                .iter()
+                .collect();
+            tags.sort();
+            assert_eq!(tags, ["label", "outline"]);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use bevy_ecs::prelude::*;
+use bevy_utils::HashMap;
+
+#[cfg(feature = "debug")]
+use bevy_utils::tracing::{error, warn};
+
+use moonshine_core::prelude::*;
+
+#[cfg(feature = "debug")]
+use crate::ViewWorld;
+use crate::{is_disappearing, PreviewView, View, Viewable};
+
+/// Panics if the link between any [`Viewable`] and [`View`] of [`Kind`] `T` is broken.
+///
+/// The following invariants are checked:
+/// - Every [`Viewable<T>`] refers to an entity with a [`View<T>`].
+/// - Every [`View<T>`] refers to a viewable entity whose [`Viewable<T>`] refers back to it.
+/// - No two [`View<T>`] entities refer to the same viewable entity.
+///
+/// [`PreviewView`] and [disappearing](crate::RegisterView::add_view_transitions) entities are not checked.
+///
+/// This is intended to be used in tests, after views have been spawned or despawned.
+/// With the `debug` feature enabled, these invariants are also checked at the end of every update.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins).add_viewable::<Bird>();
+///
+/// let bird = app.world_mut().spawn(Bird).id();
+/// app.update();
+/// moonshine_view::assert_view_invariants::<Bird>(app.world());
+///
+/// app.world_mut().despawn(bird);
+/// app.update();
+/// moonshine_view::assert_view_invariants::<Bird>(app.world());
+/// ```
+pub fn assert_view_invariants<T: Kind>(world: &World) {
+    let violations = view_invariant_violations::<T>(world);
+    assert!(violations.is_empty(), "{}", violations.join("\n"));
+}
+
+fn view_invariant_violations<T: Kind>(world: &World) -> Vec<String> {
+    let name = T::debug_name();
+    let mut violations = Vec::new();
+
+    let mut viewed = HashMap::<Entity, Entity>::default();
+    for entity in world.iter_entities() {
+        if let Some(view_data) = entity
+            .get::<View<T>>()
+            .filter(|_| !entity.contains::<PreviewView>() && !is_disappearing(&entity))
+        {
+            let view = entity.id();
+            let viewable = view_data.viewable().entity();
+            if let Some(other) = viewed.insert(viewable, view) {
+                violations.push(format!(
+                    "{viewable:?} has multiple views of {name}: {other:?} and {view:?}"
+                ));
+            }
+            if world
+                .get::<Viewable<T>>(viewable)
+                .is_none_or(|viewable| viewable.view().entity() != view)
+            {
+                violations.push(format!("{view:?} is not the view of {name} {viewable:?}"));
+            }
+        }
+
+        if let Some(viewable_data) = entity.get::<Viewable<T>>() {
+            let viewable = entity.id();
+            let view = viewable_data.view().entity();
+            if world.get::<View<T>>(view).is_none() {
+                violations.push(format!(
+                    "{viewable:?} refers to {view:?} which is not a view of {name}"
+                ));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Logs an error for every broken link between any [`Viewable`] and [`View`] of [`Kind`] `T`.
+///
+/// See [`assert_view_invariants`] for details.
+#[cfg(feature = "debug")]
+pub fn check_view_invariants<T: Kind>(world: &World) {
+    for violation in view_invariant_violations::<T>(world) {
+        error!("{violation}");
+    }
+}
+
+/// Warns about views of [`Kind`] `T` whose viewable was despawned, but which still exist after cleanup.
+///
+/// This includes views which still have a [`View<T>`] referring to a despawned viewable, and views which were
+/// [unlinked](crate::RegisterView::add_view_linked) from their viewable and have not been despawned or adopted since.
+/// [Disappearing](crate::RegisterView::add_view_transitions) views are not counted. This is a guardrail against leaking views.
+/// It only reports when the orphan count changes.
+#[cfg(feature = "debug")]
+pub fn warn_orphan_views<T: Kind>(world: &World, mut reported: Local<usize>) {
+    let count = orphan_view_count::<T>(world);
+    if count != *reported {
+        if count > 0 {
+            warn!("{count} orphan view(s) of {} detected", T::debug_name());
+        }
+        *reported = count;
+    }
+}
+
+#[cfg(feature = "debug")]
+fn orphan_view_count<T: Kind>(world: &World) -> usize {
+    world
+        .orphaned_views::<T>()
+        .into_iter()
+        .filter(|&orphan| !is_disappearing(&world.entity(orphan)))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    #[cfg(feature = "debug")]
+    use bevy_ecs::system::RunSystemOnce;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins);
+        app
+    }
+
+    #[test]
+    fn invariants_hold_across_spawn_despawn_cycles() {
+        let mut app = app();
+        let mut nodes = Vec::new();
+        for cycle in 0..3 {
+            nodes.extend((0..3).map(|_| app.world_mut().spawn(Node).id()));
+            app.update();
+            assert_view_invariants::<Node>(app.world());
+
+            for node in nodes.drain(..2 + cycle % 2) {
+                app.world_mut().despawn(node);
+            }
+            app.update();
+            assert_view_invariants::<Node>(app.world());
+            assert_eq!(app.world().view_count::<Node>(), nodes.len());
+        }
+    }
+
+    #[test]
+    fn broken_link_is_a_violation() {
+        let mut app = app();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        assert!(view_invariant_violations::<Node>(app.world()).is_empty());
+
+        // SAFE: `node` is a `Node`.
+        let viewable = unsafe { Instance::<Node>::from_entity_unchecked(node) };
+        app.world_mut().spawn(View { viewable });
+        let violations = view_invariant_violations::<Node>(app.world());
+        assert_eq!(violations.len(), 2, "{violations:?}");
+    }
+
+    #[test]
+    fn violations_use_kind_label() {
+        struct Lamp;
+
+        impl Kind for Lamp {
+            type Filter = With<Node>;
+
+            fn debug_name() -> String {
+                "Street Lamp".to_owned()
+            }
+        }
+
+        impl BuildView for Lamp {
+            fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+        }
+
+        let mut app = app();
+        app.add_viewable::<Lamp>();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+
+        // SAFE: `node` is a `Node`, and therefore a `Lamp`.
+        let viewable = unsafe { Instance::<Lamp>::from_entity_unchecked(node) };
+        app.world_mut().spawn(View { viewable });
+        let violations = view_invariant_violations::<Lamp>(app.world());
+        assert!(!violations.is_empty());
+        assert!(
+            violations
+                .iter()
+                .all(|violation| violation.contains("Street Lamp")),
+            "{violations:?}"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_view_invariants_panics_on_violation() {
+        let mut app = app();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        // SAFE: `node` is a `Node`.
+        let viewable = unsafe { Instance::<Node>::from_entity_unchecked(node) };
+        app.world_mut().spawn(View { viewable });
+        assert_view_invariants::<Node>(app.world());
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn warn_orphan_views_counts_unlinked_views() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_linked::<Node>(false);
+
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(orphan_view_count::<Node>(app.world()), 0);
+
+        app.world_mut().despawn(node);
+        app.update();
+        assert!(app.world().get_entity(view).is_ok());
+        app.world_mut()
+            .run_system_once(warn_orphan_views::<Node>)
+            .unwrap();
+        assert_eq!(orphan_view_count::<Node>(app.world()), 1);
+
+        app.world_mut().despawn(view);
+        assert_eq!(orphan_view_count::<Node>(app.world()), 0);
+    }
+}
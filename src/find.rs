@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+type Finder<T> = Box<dyn Fn(&World, Instance<T>) -> Option<Entity> + Send + Sync>;
+
+/// A [`Resource`] which stores the functions added using [`add_view_finder`](crate::RegisterView::add_view_finder).
+#[derive(Resource)]
+pub struct ViewFinders<T: Kind> {
+    finders: Vec<Finder<T>>,
+}
+
+impl<T: Kind> Default for ViewFinders<T> {
+    fn default() -> Self {
+        Self {
+            finders: Vec::new(),
+        }
+    }
+}
+
+impl<T: Kind> ViewFinders<T> {
+    pub fn add(
+        &mut self,
+        finder: impl Fn(&World, Instance<T>) -> Option<Entity> + Send + Sync + 'static,
+    ) {
+        self.finders.push(Box::new(finder));
+    }
+
+    /// Returns the first existing entity found for the given viewable instance, if any.
+    pub fn find(&self, world: &World, instance: Instance<T>) -> Option<Entity> {
+        self.finders
+            .iter()
+            .find_map(|finder| finder(world, instance))
+    }
+}
+
+type Allocator = Box<dyn FnMut(&mut World) -> Entity + Send + Sync>;
+
+/// A [`Resource`] which stores the function set using [`add_view_allocator`](crate::RegisterView::add_view_allocator).
+#[derive(Resource)]
+pub struct ViewAllocator<T: Kind> {
+    allocator: Allocator,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewAllocator<T> {
+    pub fn new(allocator: impl FnMut(&mut World) -> Entity + Send + Sync + 'static) -> Self {
+        Self {
+            allocator: Box::new(allocator),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a new view entity.
+    pub fn allocate(&mut self, world: &mut World) -> Entity {
+        (self.allocator)(world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Placed(Entity);
+
+    #[test]
+    fn found_entity_is_adopted() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_finder::<Node>(|world, instance| {
+                world
+                    .get::<Placed>(instance.entity())
+                    .map(|placed| placed.0)
+            });
+        let placed = app.world_mut().spawn_empty().id();
+        let node = app.world_mut().spawn((Node, Placed(placed))).id();
+        let other = app.world_mut().spawn(Node).id();
+        let entities = app.world().entities().len();
+        app.update();
+
+        assert_eq!(
+            app.world().view::<Node>(node).map(|view| view.entity()),
+            Some(placed)
+        );
+        assert!(app.world().get::<View<Node>>(placed).is_some());
+        let view = app.world().view::<Node>(other).unwrap().entity();
+        assert_ne!(view, placed);
+        assert_eq!(app.world().entities().len(), entities + 1);
+    }
+
+    #[test]
+    fn allocated_entities_are_views() {
+        #[derive(Resource, Default)]
+        struct Allocated(Vec<Entity>);
+
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Allocated>()
+            .add_view_allocator::<Node>(|world| {
+                let entity = world.spawn_empty().id();
+                world.resource_mut::<Allocated>().0.push(entity);
+                entity
+            });
+        let nodes: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn(Node).id()).collect();
+        app.update();
+
+        let mut views: Vec<Entity> = nodes
+            .iter()
+            .map(|&node| app.world().view::<Node>(node).unwrap().entity())
+            .collect();
+        views.sort();
+        let mut allocated = app.world().resource::<Allocated>().0.clone();
+        allocated.sort();
+        assert_eq!(views, allocated);
+    }
+}
@@ -0,0 +1,83 @@
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_gizmos::prelude::*;
+use bevy_transform::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// A [`Resource`] which controls debug drawing added using [`add_view_gizmos`](crate::RegisterView::add_view_gizmos).
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ViewGizmos {
+    /// Whether lines are drawn between viewables and their views. Enabled by default.
+    pub enabled: bool,
+    /// The color of each line.
+    pub color: Color,
+}
+
+impl Default for ViewGizmos {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// Draws a line from each viewable of [`Kind`] `T` to its view.
+///
+/// Viewables or views without a [`GlobalTransform`] are skipped.
+pub fn draw_view_gizmos<T: Kind>(
+    viewables: Query<(&GlobalTransform, &Viewable<T>)>,
+    views: Query<&GlobalTransform>,
+    config: Res<ViewGizmos>,
+    mut gizmos: Gizmos,
+) {
+    if !config.enabled {
+        return;
+    }
+    for (transform, viewable) in viewables.iter() {
+        let Ok(view_transform) = views.get(viewable.view().entity()) else {
+            continue;
+        };
+        gizmos.line(
+            transform.translation(),
+            view_transform.translation(),
+            config.color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+    use bevy::MinimalPlugins;
+    use bevy_gizmos::{config::GizmoConfigStore, GizmoPlugin};
+    use bevy_transform::TransformPlugin;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[test]
+    fn views_without_transform_are_skipped() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            AssetPlugin::default(),
+            GizmoPlugin,
+        ))
+        .add_view_gizmos::<Node>();
+        let node = app.world_mut().spawn((Node, Transform::default())).id();
+        app.world_mut().spawn(Node);
+        app.update();
+        app.update();
+
+        assert!(app.world().contains_resource::<GizmoConfigStore>());
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert!(app.world().get::<GlobalTransform>(view).is_none());
+    }
+}
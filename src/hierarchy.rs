@@ -0,0 +1,216 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::entity::Entities;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{View, Viewable};
+
+/// Parents the view of each viewable of [`Kind`] `T` under the view of its parent viewable, if it has one.
+///
+/// A view is re-evaluated when its viewable's parent changes, or when the viewable or its parent gets a new view.
+/// This allows parent views to be built after their children. A view is only unparented if it was previously
+/// parented under another view of the same kind, so that any other parent assigned by the user is retained.
+#[allow(clippy::type_complexity)]
+pub fn mirror_view_hierarchy<T: Kind>(
+    changed: Query<
+        Entity,
+        (
+            With<Viewable<T>>,
+            Or<(Changed<Parent>, Changed<Viewable<T>>)>,
+        ),
+    >,
+    mut orphaned: RemovedComponents<Parent>,
+    viewables: Query<(&Viewable<T>, Option<&Parent>)>,
+    children: Query<&Children>,
+    views: Query<Option<&Parent>, With<View<T>>>,
+    mut commands: Commands,
+) {
+    let mirror = |entity: Entity, commands: &mut Commands| {
+        let Ok((viewable, parent)) = viewables.get(entity) else {
+            return;
+        };
+        let view = viewable.view().entity();
+        let Ok(current) = views.get(view) else {
+            return;
+        };
+        let current = current.map(Parent::get);
+        let target = parent
+            .and_then(|parent| viewables.get(parent.get()).ok())
+            .map(|(parent_viewable, _)| parent_viewable.view().entity());
+        if current == target {
+            return;
+        }
+        match target {
+            Some(target) => {
+                commands.entity(view).set_parent(target);
+            }
+            None => {
+                if current.is_some_and(|current| views.contains(current)) {
+                    commands.entity(view).remove_parent();
+                }
+            }
+        }
+    };
+
+    for entity in changed.iter().chain(orphaned.read()) {
+        mirror(entity, &mut commands);
+        for &child in children.get(entity).into_iter().flatten() {
+            mirror(child, &mut commands);
+        }
+    }
+}
+
+/// A [`Resource`] which stores the root entity set using [`add_view_root`](crate::RegisterView::add_view_root).
+#[derive(Resource)]
+pub struct ViewRoot<T: Kind> {
+    root: Entity,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewRoot<T> {
+    pub fn new(root: Entity) -> Self {
+        Self {
+            root,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Parents each new view of [`Kind`] `T` under the [`ViewRoot<T>`], if it still exists.
+pub fn parent_view_root<T: Kind>(
+    query: Query<&Viewable<T>, Added<Viewable<T>>>,
+    views: Query<(), (With<View<T>>, Without<Parent>)>,
+    root: Res<ViewRoot<T>>,
+    entities: &Entities,
+    mut commands: Commands,
+) {
+    if !entities.contains(root.root) {
+        return;
+    }
+    for viewable in query.iter() {
+        let view = viewable.view().entity();
+        if views.contains(view) {
+            commands.entity(view).set_parent(root.root);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::assert_view_invariants;
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins).add_view_hierarchy::<Node>();
+        app
+    }
+
+    fn parent_of(app: &ViewTestApp, entity: Entity) -> Option<Entity> {
+        app.world().get::<Parent>(entity).map(Parent::get)
+    }
+
+    #[test]
+    fn two_level_tree_is_mirrored() {
+        let mut app = app();
+        let child = app.world_mut().spawn(Node).id();
+        let root = app.world_mut().spawn(Node).add_child(child).id();
+        let leaf = app.world_mut().spawn(Node).set_parent(child).id();
+        app.update();
+
+        let root_view = app.world().view::<Node>(root).unwrap().entity();
+        let child_view = app.world().view::<Node>(child).unwrap().entity();
+        let leaf_view = app.world().view::<Node>(leaf).unwrap().entity();
+        assert_eq!(parent_of(&app, root_view), None);
+        assert_eq!(parent_of(&app, child_view), Some(root_view));
+        assert_eq!(parent_of(&app, leaf_view), Some(child_view));
+    }
+
+    #[test]
+    fn parent_view_built_after_child_view() {
+        let mut app = app();
+        let child = app.world_mut().spawn(Node).id();
+        app.update();
+        let child_view = app.world().view::<Node>(child).unwrap().entity();
+
+        let parent = app.world_mut().spawn(Node).add_child(child).id();
+        app.update();
+        let parent_view = app.world().view::<Node>(parent).unwrap().entity();
+        assert_eq!(parent_of(&app, child_view), Some(parent_view));
+    }
+
+    #[test]
+    fn child_view_survives_parent_view_despawn() {
+        let mut app = app();
+        let child = app.world_mut().spawn(Node).id();
+        let parent = app.world_mut().spawn(Node).add_child(child).id();
+        app.update();
+        let parent_view = app.world().view::<Node>(parent).unwrap().entity();
+        let child_view = app.world().view::<Node>(child).unwrap().entity();
+
+        app.world_mut().entity_mut(parent).remove::<Node>();
+        app.update();
+        assert!(app.world().get_entity(parent_view).is_err());
+        assert!(app.world().get_entity(child_view).is_ok());
+        assert_eq!(parent_of(&app, child_view), None);
+
+        app.world_mut().entity_mut(parent).insert(Node);
+        app.update();
+        let parent_view = app.world().view::<Node>(parent).unwrap().entity();
+        assert_eq!(parent_of(&app, child_view), Some(parent_view));
+
+        app.world_mut().entity_mut(child).remove_parent();
+        app.update();
+        assert_eq!(parent_of(&app, child_view), None);
+    }
+
+    #[test]
+    fn child_view_despawned_with_child() {
+        let mut app = app();
+        let child = app.world_mut().spawn(Node).id();
+        let parent = app.world_mut().spawn(Node).add_child(child).id();
+        app.update();
+        let parent_view = app.world().view::<Node>(parent).unwrap().entity();
+        let child_view = app.world().view::<Node>(child).unwrap().entity();
+
+        app.world_mut().entity_mut(child).despawn();
+        app.update();
+        assert!(app.world().get_entity(child_view).is_err());
+        assert!(app
+            .world()
+            .get::<Children>(parent_view)
+            .is_none_or(|c| c.is_empty()));
+        assert_view_invariants::<Node>(app.world());
+    }
+
+    #[test]
+    fn new_views_parented_under_root() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins);
+        let root = app.world_mut().spawn_empty().id();
+        app.add_view_root::<Node>(root);
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(parent_of(&app, view), Some(root));
+
+        app.world_mut().entity_mut(root).despawn_recursive();
+        app.update();
+        let respawned = app.world().view::<Node>(node).unwrap().entity();
+        assert!(respawned != view);
+        assert_eq!(parent_of(&app, respawned), None);
+
+        let other = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(other).unwrap().entity();
+        assert_eq!(parent_of(&app, view), None);
+    }
+}
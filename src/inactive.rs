@@ -0,0 +1,130 @@
+use bevy_ecs::prelude::*;
+#[cfg(feature = "render")]
+use bevy_render::prelude::*;
+use bevy_utils::tracing::debug;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// A [`Component`] which is inserted on new views of kinds registered using
+/// [`add_view_inactive`](crate::RegisterView::add_view_inactive).
+///
+/// An inactive view is built as usual, but is hidden until [`activate_view`] is called.
+/// Systems which configure new views may use this marker to exclude views which are already active.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct ViewInactive;
+
+/// The [`Visibility`] of an inactive view as it was built, restored by [`activate_view`].
+#[cfg(feature = "render")]
+#[derive(Component)]
+struct InactiveVisibility(Visibility);
+
+/// Inserts [`ViewInactive`] into each new view of [`Kind`] `T`, and hides it if the `render` feature is enabled.
+pub fn insert_view_inactive<T: Kind>(
+    query: Query<&Viewable<T>, Added<Viewable<T>>>,
+    #[cfg(feature = "render")] visibility: Query<&Visibility>,
+    mut commands: Commands,
+) {
+    for viewable in query.iter() {
+        let view = viewable.view().entity();
+        commands.entity(view).insert((
+            ViewInactive,
+            #[cfg(feature = "render")]
+            InactiveVisibility(visibility.get(view).copied().unwrap_or_default()),
+            #[cfg(feature = "render")]
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// Removes [`ViewInactive`] from the [`View`](crate::View) of the given viewable instance.
+///
+/// If the `render` feature is enabled, the [`Visibility`](bevy_render::prelude::Visibility) of the view is also
+/// restored to what it was when the view was built, which is [`Inherited`](bevy_render::prelude::Visibility::Inherited)
+/// by default. Nothing happens if the viewable has no view, or if its view is already active.
+pub fn activate_view<T: Kind>(viewable: Instance<T>, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        let Some(view) = world
+            .get::<Viewable<T>>(viewable.entity())
+            .map(Viewable::view)
+        else {
+            return;
+        };
+        let Ok(mut view_entity) = world.get_entity_mut(view.entity()) else {
+            return;
+        };
+        if view_entity.take::<ViewInactive>().is_none() {
+            return;
+        }
+        #[cfg(feature = "render")]
+        {
+            let visibility = view_entity
+                .take::<InactiveVisibility>()
+                .map_or_else(Visibility::default, |built| built.0);
+            view_entity.insert(visibility);
+        }
+        debug!("{view:?} activated for {viewable:?}");
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[cfg(feature = "render")]
+    #[derive(Component)]
+    struct Lamp;
+
+    #[cfg(feature = "render")]
+    impl BuildView for Lamp {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.insert(Visibility::Visible);
+        }
+    }
+
+    fn activate<T: Kind>(app: &mut ViewTestApp, entity: Entity) {
+        // SAFE: Caller guarantees `entity` is of kind `T`.
+        let viewable = unsafe { Instance::<T>::from_entity_unchecked(entity) };
+        activate_view(viewable, &mut app.world_mut().commands());
+        app.world_mut().flush();
+    }
+
+    #[test]
+    fn view_is_inactive_until_activated() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins).add_view_inactive::<Node>();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert!(app.world().get::<ViewInactive>(view).is_some());
+
+        activate::<Node>(&mut app, node);
+        assert!(app.world().get::<ViewInactive>(view).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "render")]
+    fn activation_restores_built_visibility() {
+        let mut app = ViewTestApp::with_viewable::<Lamp>();
+        app.add_plugins(MinimalPlugins).add_view_inactive::<Lamp>();
+        let lamp = app.world_mut().spawn(Lamp).id();
+        app.update();
+        let view = app.world().view::<Lamp>(lamp).unwrap().entity();
+        assert_eq!(
+            app.world().get::<Visibility>(view),
+            Some(&Visibility::Hidden)
+        );
+
+        activate::<Lamp>(&mut app, lamp);
+        assert_eq!(
+            app.world().get::<Visibility>(view),
+            Some(&Visibility::Visible)
+        );
+    }
+}
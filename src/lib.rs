@@ -1,309 +1,4131 @@
 #![doc = include_str!("../README.md")]
 
 use std::any::TypeId;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 
 use bevy_app::prelude::*;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::entity::{EntityMapper, MapEntities};
 use bevy_ecs::prelude::*;
+use bevy_ecs::query::QueryFilter;
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::{ReflectComponent, ReflectMapEntities};
+use bevy_ecs::system::IntoObserverSystem;
+use bevy_ecs::world::{CommandQueue, DeferredWorld};
 use bevy_hierarchy::prelude::*;
-use bevy_utils::{tracing::debug, HashMap, HashSet};
+#[cfg(feature = "reflect")]
+use bevy_reflect::Reflect;
+#[cfg(feature = "save")]
+use bevy_reflect::TypePath;
+use bevy_utils::{
+    tracing::{debug, field, info_span, warn},
+    HashMap, HashSet,
+};
 
-use moonshine_core::{check::CheckSystems, prelude::*};
+use moonshine_core::{check::CheckSystems, load::LoadSystem, prelude::*};
 
 pub mod prelude {
-    pub use super::{BuildView, RegisterView, View, ViewCommands, Viewable, Viewables};
+    #[cfg(feature = "debug")]
+    pub use super::ViewDecorations;
+    #[cfg(feature = "gizmos")]
+    pub use super::ViewGizmos;
+    #[cfg(feature = "transform")]
+    pub use super::ViewTransform;
+    #[cfg(feature = "metrics")]
+    pub use super::{NoViewsRemaining, ViewMetrics};
+    #[cfg(feature = "transition")]
+    pub use super::{ViewAppearing, ViewDespawnDelay, ViewDisappearing};
+
+    pub use super::{
+        BuildView, DespawnedViews, IsView, IsViewable, LazyView, NewViews, NotView, PreviewView,
+        RegisterView, RemoteViews, SharedView, SharedViewChild, SharedViewable, View, ViewCommands,
+        ViewComponentChanged, ViewDestroyed, ViewDormant, ViewInactive, ViewOrphaned, ViewQuery,
+        ViewRebuildKey, ViewSystems, ViewUnload, ViewWorld, Viewable, Viewables, ViewsPaused,
+        ViewsWhere,
+    };
+}
+
+#[cfg(feature = "asset")]
+mod asset;
+mod budget;
+mod builder;
+mod change;
+mod debounce;
+mod decorate;
+mod diagnostics;
+mod find;
+#[cfg(feature = "gizmos")]
+mod gizmos;
+mod hierarchy;
+mod inactive;
+mod lod;
+mod metrics;
+mod preserve;
+mod query;
+#[cfg(feature = "reflect")]
+mod reflect;
+mod remote;
+#[cfg(feature = "save")]
+mod save;
+mod settle;
+mod shared;
+#[cfg(feature = "render")]
+mod spatial;
+#[cfg(feature = "state")]
+mod state;
+mod sync;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
+#[cfg(feature = "transform")]
+mod transform;
+#[cfg(feature = "transition")]
+mod transition;
+mod veto;
+mod writeback;
+
+pub use builder::ViewKindBuilder;
+pub use change::{ViewComponentChanged, ViewRebuildKey};
+pub use decorate::decorate_view;
+#[cfg(feature = "debug")]
+pub use decorate::ViewDecorations;
+pub use diagnostics::assert_view_invariants;
+#[cfg(feature = "gizmos")]
+pub use gizmos::ViewGizmos;
+pub use inactive::{activate_view, ViewInactive};
+pub use metrics::DespawnedViews;
+#[cfg(feature = "metrics")]
+pub use metrics::{NoViewsRemaining, ViewMetrics};
+pub use query::{LazyView, NewViews, ViewQuery, ViewsWhere};
+pub use remote::{build_views_into, RemoteViews};
+pub use shared::{SharedView, SharedViewable};
+#[cfg(feature = "transform")]
+pub use transform::ViewTransform;
+#[cfg(feature = "transition")]
+pub use transition::{ViewAppearing, ViewDespawnDelay, ViewDisappearing};
+
+/// Extension trait used to register views using an [`App`].
+///
+/// # Example
+///
+/// All views are registered using [`Kind`] and [`BuildView`] bounds, so they may be registered generically:
+/// ```
+/// # use std::marker::PhantomData;
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// struct ViewPlugin<T: BuildView>(PhantomData<T>);
+///
+/// impl<T: BuildView> Plugin for ViewPlugin<T> {
+///     fn build(&self, app: &mut App) {
+///         app.add_viewable::<T>().add_systems(Update, sync_views::<T>);
+///     }
+/// }
+///
+/// fn sync_views<T: Kind>(query: Query<(Instance<T>, &Viewable<T>)>, views: Query<&View<T>>) {
+///     for (instance, viewable) in query.iter() {
+///         let view: Instance<View<T>> = viewable.view();
+///         assert_eq!(views.get(view.entity()).unwrap().viewable(), instance);
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// #[derive(Component)]
+/// struct Fish;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// impl BuildView for Fish {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((
+///     MinimalPlugins,
+///     ViewPlugin::<Bird>(PhantomData),
+///     ViewPlugin::<Fish>(PhantomData),
+/// ));
+/// app.world_mut().spawn(Bird);
+/// app.world_mut().spawn(Fish);
+/// app.update();
+/// ```
+pub trait RegisterView {
+    /// Adds a view for a given [`Kind`].
+    fn add_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self;
+
+    /// Adds a given [`Kind`] as viewable.
+    fn add_viewable<T: BuildView>(&mut self) -> &mut Self {
+        self.add_view::<T, T>()
+    }
+
+    /// Returns a [`ViewKindBuilder`] used to configure the views of [`Kind`] `T` in a single chain.
+    ///
+    /// This is an alternative to calling each `add_view_*` method with the same kind.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Shape;
+    ///
+    /// impl BuildView for Shape {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.view_kind::<Shape>()
+    ///     .view::<Shape>()
+    ///     .hierarchy()
+    ///     .priority(10)
+    ///     .finish()
+    ///     .add_plugins(MinimalPlugins);
+    /// ```
+    fn view_kind<T: Kind>(&mut self) -> ViewKindBuilder<'_, T>;
+
+    /// Adds a fallback view for a given [`Kind`], built only if no other view variant was built for it.
+    ///
+    /// A view variant is any view added using `add_view::<T, V>` where `V` is not `T` itself.
+    /// The fallback is built after all other views, and only once per view. If a variant starts matching
+    /// after the fallback was built, it is built in addition to the fallback.
+    fn add_view_fallback<T: Kind, F: BuildView<T>>(&mut self) -> &mut Self;
+
+    /// Adds a [`Component`] as viewable, without a [`BuildView`] implementation.
+    ///
+    /// Every [`Component`] is a [`Kind`] with a filter of `With<C>`, so any existing marker component may
+    /// be viewable. A [`View<C>`] is spawned for each entity with `C`, but nothing else is inserted into it.
+    /// Use [`add_view_build_system`](RegisterView::add_view_build_system) or [`NewViews<C>`] to populate the views.
+    fn add_view_component<C: Component>(&mut self) -> &mut Self;
+
+    /// Sets the build priority of views of [`Kind`] `T`.
+    ///
+    /// Within [`ViewSystems::Build`], views of kinds with a lower priority are built first, and their commands
+    /// are applied before views of kinds with a higher priority are built. This is useful when building one
+    /// view depends on another, such as building terrain before units placed on it.
+    ///
+    /// Kinds without a priority are not ordered relative to any other kind. Priorities only apply to the
+    /// [`BuildView`] implementations registered with [`add_view`](RegisterView::add_view). Any custom systems
+    /// should instead be ordered relative to [`ViewSystems::Build`].
+    fn add_view_priority<T: Kind>(&mut self, priority: i32) -> &mut Self;
+
+    /// Mirrors the hierarchy of viewables of [`Kind`] `T` onto their views.
+    ///
+    /// When a viewable is a child of another viewable of the same kind, its view becomes a child of the parent's view.
+    fn add_view_hierarchy<T: Kind>(&mut self) -> &mut Self;
+
+    /// Parents every new view of [`Kind`] `T` under the given root entity, unless it already has a parent.
+    ///
+    /// This is useful to keep views organized in the scene hierarchy. If the root entity is despawned recursively,
+    /// its views are despawned with it and respawned as root entities.
+    fn add_view_root<T: Kind>(&mut self, root: Entity) -> &mut Self;
+
+    /// Inserts the initial [`Transform`](bevy_transform::prelude::Transform) of views of [`Kind`] `T` using [`ViewTransform`].
+    ///
+    /// The transform is inserted before any view is built, so a [`BuildView`] implementation may still override it.
+    #[cfg(feature = "transform")]
+    fn add_view_transform<T: ViewTransform>(&mut self) -> &mut Self;
+
+    /// Keeps the [`Transform`](bevy_transform::prelude::Transform) of views of [`Kind`] `T` at a fixed offset
+    /// from the transform of their viewable.
+    ///
+    /// The view transform is updated in [`PostUpdate`] whenever the viewable transform changes, before transforms
+    /// are propagated. Viewables without a transform are ignored.
+    ///
+    /// Only one offset may be added for each kind, including by [`add_view_synced_transform`](RegisterView::add_view_synced_transform).
+    /// Any further call is ignored with a warning, and the first offset is kept.
+    #[cfg(feature = "transform")]
+    fn add_view_offset_transform<T: Kind>(
+        &mut self,
+        offset: bevy_transform::prelude::Transform,
+    ) -> &mut Self;
+
+    /// Keeps the [`Transform`](bevy_transform::prelude::Transform) of views of [`Kind`] `T` equal to the
+    /// transform of their viewable, including translation, rotation and scale.
+    ///
+    /// This is equivalent to [`add_view_offset_transform`](RegisterView::add_view_offset_transform) with an
+    /// identity offset. Only the local transform is synchronized, before transforms are propagated, so the
+    /// [`GlobalTransform`](bevy_transform::prelude::GlobalTransform) of each view is updated in the same frame.
+    #[cfg(feature = "transform")]
+    fn add_view_synced_transform<T: Kind>(&mut self) -> &mut Self {
+        self.add_view_offset_transform::<T>(bevy_transform::prelude::Transform::IDENTITY)
+    }
+
+    /// Keeps the [`Transform`](bevy_transform::prelude::Transform) of views of [`Kind`] `T` interpolated between
+    /// the transforms of their viewable in the last two fixed updates.
+    ///
+    /// This is useful for smooth views of viewables which are updated in [`FixedUpdate`](bevy_app::FixedUpdate).
+    /// Viewable transforms are recorded in [`FixedPostUpdate`](bevy_app::FixedPostUpdate), and each view transform
+    /// is interpolated using the [overstep fraction](bevy_time::Fixed) in [`PostUpdate`], before transforms are propagated.
+    /// This means views lag their viewable by up to one fixed update.
+    ///
+    /// If a viewable moves farther than `snap` within a single fixed update, such as when it is teleported,
+    /// its view is snapped to the new transform instead. Viewables without a transform are ignored.
+    #[cfg(feature = "transform")]
+    fn add_view_interpolated_transform<T: Kind>(&mut self, snap: f32) -> &mut Self;
+
+    /// Ensures views of [`Kind`] `T` have all the spatial components required to be rendered.
+    ///
+    /// After a view is built, [`Transform`](bevy_transform::prelude::Transform) and
+    /// [`Visibility`](bevy_render::prelude::Visibility) are inserted into it, unless it already has them.
+    /// This prevents views from being invisible because a [`BuildView`] implementation did not insert them.
+    #[cfg(feature = "render")]
+    fn add_view_spatial<T: Kind>(&mut self) -> &mut Self;
+
+    /// Draws a line from each viewable of [`Kind`] `T` to its view using [`Gizmos`](bevy_gizmos::prelude::Gizmos).
+    ///
+    /// This is a development aid for spatial views, which may be toggled using the [`ViewGizmos`] resource.
+    /// Lines are drawn in [`PostUpdate`] after transforms are propagated, and only if gizmos are enabled in the app.
+    /// Viewables or views without a [`GlobalTransform`](bevy_transform::prelude::GlobalTransform) are skipped.
+    ///
+    /// Text labels are not drawn, since [`Gizmos`](bevy_gizmos::prelude::Gizmos) do not support text.
+    #[cfg(feature = "gizmos")]
+    fn add_view_gizmos<T: Kind>(&mut self) -> &mut Self;
+
+    /// Sends a [`ViewComponentChanged<T, C>`] event whenever [`Component`] `C` of a view of [`Kind`] `T` is changed.
+    ///
+    /// Changes are detected in [`PostUpdate`], so changes made during [`Update`] are reported in the same update.
+    fn add_view_change_event<T: Kind, C: Component>(&mut self) -> &mut Self;
+
+    /// [Rebuilds](rebuild) all views of [`Kind`] `T` whenever any [`Asset`](bevy_asset::Asset) `A` is modified,
+    /// such as when it is hot-reloaded.
+    ///
+    /// Modifications are detected in [`PreUpdate`] before [`ViewSystems::Spawn`], so new views are built in the same update.
+    ///
+    /// Every view of `T` is rebuilt, regardless of which asset was modified. If only some views depend on
+    /// the modified asset, read [`AssetEvent<A>`](bevy_asset::AssetEvent) yourself and [`rebuild`] only those views instead.
+    #[cfg(feature = "asset")]
+    fn add_view_rebuild_on_asset<T: Kind, A: bevy_asset::Asset>(&mut self) -> &mut Self;
+
+    /// [Rebuilds](rebuild) the view of a viewable of [`Kind`] `T` whenever its [`Component`] `C` is changed.
+    ///
+    /// Changes are detected in [`PreUpdate`] before [`ViewSystems::Spawn`], so new views are built in the same update.
+    /// Insertion of the component, such as when the viewable is spawned, is not considered a change.
+    ///
+    /// This is useful for views which represent a group of entities, such as a single health bar for a squad.
+    /// Any entity may be viewable, so the group itself is the viewable entity, and its members are stored in a
+    /// component which is read when the view is built. Registering that component rebuilds the view whenever
+    /// members are added or removed:
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Squad {
+    ///     members: Vec<Entity>,
+    /// }
+    ///
+    /// impl BuildView for Squad {
+    ///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+    ///         let squad = world.get::<Squad>(object.entity()).unwrap();
+    ///         // Build a single view for all `squad.members` ...
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_viewable::<Squad>()
+    ///     .add_view_rebuild_on_change::<Squad, Squad>();
+    /// ```
+    ///
+    /// Changes to the members themselves do not rebuild the view. Instead, update the view in place using
+    /// [`ViewQuery`] or a [build system](RegisterView::add_view_build_system).
+    fn add_view_rebuild_on_change<T: Kind, C: Component>(&mut self) -> &mut Self;
+
+    /// [Rebuilds](rebuild) the view of a viewable of [`Kind`] `T` whenever its [`Component`] `C` is changed, but only
+    /// if its [`ViewRebuildKey`] differs from the key its view was built with.
+    ///
+    /// This is similar to [`add_view_rebuild_on_change`](RegisterView::add_view_rebuild_on_change), except that
+    /// changes which leave the key equal are ignored. This is useful to avoid expensive rebuilds if `C` is changed
+    /// often, but only part of it is relevant to the view:
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Squad {
+    ///     members: Vec<Entity>,
+    ///     morale: f32,
+    /// }
+    ///
+    /// impl BuildView for Squad {
+    ///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+    ///         // Build a single view for all members ...
+    ///     }
+    /// }
+    ///
+    /// impl ViewRebuildKey for Squad {
+    ///     type Key = usize;
+    ///
+    ///     fn rebuild_key(world: &World, object: Object<Self>) -> usize {
+    ///         world.get::<Squad>(object.entity()).unwrap().members.len()
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_viewable::<Squad>()
+    ///     .add_view_rebuild_on_key::<Squad, Squad>();
+    /// ```
+    fn add_view_rebuild_on_key<T: ViewRebuildKey, C: Component>(&mut self) -> &mut Self;
+
+    /// Rebuilds the [`View`] of every [`Viewable`] of [`Kind`] `T` which gains or loses [`Component`] `C`.
+    ///
+    /// This is useful for kinds whose view structure depends on optional components, such as a unit which gains
+    /// a shield module. Insertion and removal of `C` are observed, and each affected view is [rebuilt](rebuild)
+    /// at most once per update, before [`ViewSystems::Spawn`], regardless of how many times `C` was added or
+    /// removed. Viewables which don't have a view yet are ignored, since their views are built with the
+    /// current components anyway.
+    fn add_view_rebuild_on<T: Kind, C: Component>(&mut self) -> &mut Self;
+
+    /// Marks new views of [`Kind`] `T` as [`ViewInactive`] after they're built, until [`activate_view`] is called.
+    ///
+    /// This allows views to be configured by other systems before they become active.
+    /// If the `render` feature is enabled, inactive views are also hidden.
+    fn add_view_inactive<T: Kind>(&mut self) -> &mut Self;
+
+    /// Marks views of [`Kind`] `T` as [`ViewAppearing`] when spawned, and [`ViewDisappearing`] before despawned.
+    ///
+    /// This allows views to be animated as they appear and disappear. Each marker is removed once its duration
+    /// has elapsed, at which point a disappearing view is despawned. Views which are [rebuilt](rebuild) are
+    /// despawned immediately.
+    #[cfg(feature = "transition")]
+    fn add_view_transitions<T: Kind>(&mut self, appear: Duration, disappear: Duration)
+        -> &mut Self;
+
+    /// Sets whether views of [`Kind`] `T` are despawned with their viewable entity.
+    ///
+    /// By default, views are linked, and despawned when their viewable entity is despawned.
+    /// If not linked, the [`View<T>`] component is removed from the view instead, and the entity is left for
+    /// the user to manage, such as to play a death animation before despawning it. A [`ViewOrphaned<T>`] event
+    /// is sent whenever this happens.
+    /// Views are always despawned if their viewable entity still exists, but is no longer of kind `T`.
+    fn add_view_linked<T: Kind>(&mut self, linked: bool) -> &mut Self;
+
+    /// Delays spawning and despawning views of [`Kind`] `T` until the change persists for the given number of frames.
+    ///
+    /// A view is only spawned once its viewable entity has been of kind `T` for `frames` consecutive updates,
+    /// and only despawned once it has not been of kind `T` for as many updates. This prevents views from being
+    /// rebuilt repeatedly for entities which rapidly gain and lose their kind. Views of despawned viewable entities
+    /// are still despawned immediately.
+    fn add_view_debounce<T: Kind>(&mut self, frames: u32) -> &mut Self;
+
+    /// Limits the time spent building views of [`Kind`] `T` in each frame to the given budget.
+    ///
+    /// The time spent in each [`BuildView::build`] call, including applying the commands it queues, is measured.
+    /// The number of views spawned in each frame is limited to as many as are estimated to fit within the budget,
+    /// based on the cost of views in the previous frame. Any other viewables remain pending for later frames.
+    /// This smooths frame times during bulk loads of views with varying costs.
+    ///
+    /// At least one view is spawned per frame to guarantee progress, even if it exceeds the budget.
+    fn add_view_time_budget<T: Kind>(&mut self, budget: Duration) -> &mut Self;
+
+    /// Spawns views of [`Kind`] `T` as soon as [`Component`] `C` is added to a viewable instance of `T`.
+    ///
+    /// By default, views are spawned by polling for instances of `T` without a view during [`PreUpdate`].
+    /// Instead, an observer of [`OnAdd`] for `C` is used to spawn the view immediately, and the polling system
+    /// is skipped. The view is still built during [`ViewSystems::Build`].
+    ///
+    /// This is intended for mostly static worlds, and only works for kinds defined by a single marker component.
+    /// Views are not spawned for entities which become of kind `T` without `C` being added, such as when other
+    /// components of an arbitrary [`Kind::Filter`] change, or after a view is rebuilt or woken.
+    /// Debouncing and vetoes are not applied to reactive views.
+    fn add_view_reactive<T: Kind, C: Component>(&mut self) -> &mut Self;
+
+    /// Spawns views of [`Kind`] `T` only when they are accessed using [`LazyView<T>`].
+    ///
+    /// By default, views are spawned by polling for instances of `T` without a view during [`PreUpdate`].
+    /// Instead, the polling system is skipped, and views are only spawned for instances of `T` when a
+    /// [`LazyView<T>`] is iterated. This is useful for tooling which only occasionally needs any views.
+    fn add_view_lazy<T: Kind>(&mut self) -> &mut Self;
+
+    /// Adds a predicate which may veto spawning a view of [`Kind`] `T` for a viewable instance.
+    ///
+    /// Before a view is spawned, every predicate added for `T` is called with the [`World`] and the viewable
+    /// instance. If any of them returns `true`, the view is not spawned this frame. Vetoed viewables remain
+    /// pending, since they still have no [`Viewable<T>`], and are considered again on the next update.
+    /// This is useful to limit the number of views spawned per frame, such as when a view pool is exhausted.
+    ///
+    /// Vetoes never despawn existing views.
+    fn add_view_veto<T: Kind>(
+        &mut self,
+        veto: impl Fn(&World, Instance<T>) -> bool + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Delays spawning views of [`Kind`] `T` until their viewable entity has persisted for a full update.
+    ///
+    /// Viewable entities are marked as settled in [`Last`], and views are only spawned for settled entities.
+    /// This prevents views from being built from half-initialized viewables whose components are inserted
+    /// across multiple command flushes, such as when an entity is spawned before [`ViewSystems::Spawn`] and
+    /// completed later in the same update. Entities keep their settled marker if they lose kind `T`.
+    fn add_view_settled<T: Kind>(&mut self) -> &mut Self;
+
+    /// Only spawns views of [`Kind`] `T` while the current [`State`](bevy_state::state::State) of `S` is `state`,
+    /// and despawns all of them when the state is exited.
+    ///
+    /// This is a [veto](RegisterView::add_view_veto) which applies while `S` is in any other state, and a system
+    /// which runs on [`OnExit`](bevy_state::state::OnExit) to despawn existing views. Viewable entities are kept,
+    /// so their views are spawned again once the state is re-entered.
+    #[cfg(feature = "state")]
+    fn add_view_in_state<T: Kind, S: bevy_state::state::States>(&mut self, state: S) -> &mut Self;
+
+    /// Adds a function which may find an existing [`Entity`] to use as the view of a viewable instance of [`Kind`] `T`.
+    ///
+    /// Before a view is spawned, every function added for `T` is called with the [`World`] and the viewable instance,
+    /// in order of registration. If any of them returns an entity, it is [adopted](adopt) as the view instead of
+    /// spawning a new one. The adopted view is then built as usual. This is useful for views which are pre-placed
+    /// in a scene. Finders are not applied to [reactive](RegisterView::add_view_reactive) views.
+    fn add_view_finder<T: Kind>(
+        &mut self,
+        finder: impl Fn(&World, Instance<T>) -> Option<Entity> + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Saves views of [`Kind`] `T` with their viewable entities, instead of building them again when loaded.
+    ///
+    /// By default, views are marked with [`Unload`] and are not saved, so that they are built again when their
+    /// viewable entities are loaded. Instead, new views of `T` are marked with [`Save`], and [`View<T>`] and
+    /// [`Viewable<T>`] are registered for reflection, so that the link between them is saved. When loaded, each view
+    /// is associated with its loaded viewable entity during [`PreUpdate`] after [`LoadSystem::PostLoad`], and is not
+    /// built again. This is useful for hand-authored views, such as in a level editor.
+    ///
+    /// The viewable entities must also be saved, and any components of the view must be registered for
+    /// reflection to be saved. Only the link to views of `T` itself is saved, so other view variants of the same
+    /// viewable are still built after they are loaded.
+    #[cfg(feature = "save")]
+    fn add_view_saved<T: Kind + TypePath>(&mut self) -> &mut Self;
+
+    /// Sets the function used to allocate the view entities of [`Kind`] `T`, instead of spawning them.
+    ///
+    /// When a view would be spawned, the allocator is called with exclusive access to the [`World`] when commands
+    /// are applied, and must return an entity which exists, such as one spawned using [`World::spawn_empty`].
+    /// The entity is then [adopted](adopt) as the view, and built as usual. This is useful to control view entity
+    /// IDs, such as in a networked game. [Finders](RegisterView::add_view_finder) are called before the allocator.
+    /// The allocator is not used for [reactive](RegisterView::add_view_reactive) views.
+    fn add_view_allocator<T: Kind>(
+        &mut self,
+        allocator: impl FnMut(&mut World) -> Entity + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Registers [`Kind`] `T` as viewable using [`SharedView`]s, grouped by the given key function.
+    ///
+    /// Every viewable instance of `T` is assigned to the shared view of its key, which is spawned with its first
+    /// member and despawned with its last. Keys are evaluated for every instance on each update, before
+    /// [`ViewSystems::Spawn`], so instances move between shared views as their keys change. This is useful for
+    /// batching many nearby viewables into a single view, such as for a distant level of detail.
+    ///
+    /// Shared views are a separate relationship, and do not use [`View<T>`] or [`Viewable<T>`], since those are
+    /// always one-to-one. Each member has a [`SharedViewable<T>`] instead. Do not register `T` using both
+    /// [`add_viewable`](RegisterView::add_viewable) and this, unless each instance should also have its own view.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tree;
+    ///
+    /// #[derive(Component)]
+    /// struct Cell(IVec2);
+    ///
+    /// fn build_forests(forests: Query<&SharedView<Tree>, Added<SharedView<Tree>>>) {
+    ///     for forest in forests.iter() {
+    ///         info!("{} trees", forest.len());
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_shared_viewable::<Tree, IVec2>(|world, tree| world.get::<Cell>(tree.entity()).unwrap().0)
+    ///     .add_systems(Update, build_forests);
+    /// ```
+    fn add_shared_viewable<T: Kind, K: Eq + std::hash::Hash + Send + Sync + 'static>(
+        &mut self,
+        key: impl Fn(&World, Instance<T>) -> K + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Preserves [`Component`] `C` of views of [`Kind`] `T` when they are [rebuilt](rebuild).
+    ///
+    /// When a view is rebuilt, `C` is copied from the old view and inserted into the new view after it is built.
+    /// This is useful to retain transient view state, such as selection or highlight, while iterating on views.
+    fn add_view_preserve<T: Kind, C: Component + Clone>(&mut self) -> &mut Self;
+
+    /// Runs the given system once for every new view of [`Kind`] `T`, after it is built.
+    ///
+    /// Unlike [`BuildView`], the system may use any [`SystemParam`](bevy_ecs::system::SystemParam), such as
+    /// [`ResMut`] or [`Commands`]. The view is passed to it as [`In`] input.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Bird;
+    ///
+    /// impl BuildView for Bird {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// fn build_bird_mesh(
+    ///     In(view): In<Instance<View<Bird>>>,
+    ///     mut meshes: ResMut<Assets<Mesh>>,
+    ///     mut commands: Commands,
+    /// ) {
+    ///     let mesh = meshes.add(Circle::new(10.0));
+    ///     commands.entity(view.entity()).insert(Mesh2d(mesh));
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_viewable::<Bird>()
+    ///     .add_view_build_system::<Bird, _>(build_bird_mesh);
+    /// ```
+    fn add_view_build_system<T: Kind, M>(
+        &mut self,
+        system: impl IntoSystem<In<Instance<View<T>>>, (), M> + 'static,
+    ) -> &mut Self;
+
+    /// Runs the given system once after all new views of [`Kind`] `T` are built in a frame.
+    ///
+    /// Unlike [`add_view_build_system`](RegisterView::add_view_build_system), the system runs once per frame,
+    /// and only if any views of `T` were spawned. It runs after all [`BuildView`] implementations, fallbacks and
+    /// build systems, and after all of their commands are applied. This is useful for work across all new views,
+    /// such as adding them to a spatial index. Use [`NewViews<T>`] to access the views built in this frame.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Bird;
+    ///
+    /// impl BuildView for Bird {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// fn index_bird_views(views: NewViews<Bird>) {
+    ///     for (view, viewable) in views.iter() {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_viewable::<Bird>()
+    ///     .add_view_finalizer::<Bird, _>(index_bird_views);
+    /// ```
+    fn add_view_finalizer<T: Kind, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self;
+
+    /// Runs the given system for each view of [`Kind`] `T` whose [`Children`] are changed after it is built.
+    ///
+    /// Changes are detected in [`PostUpdate`], so children added or removed during [`Update`] are reported in the
+    /// same update. This is useful to recompute data derived from the children of a view, such as its bounds.
+    /// The system is not run for views which are despawned before it runs.
+    fn add_view_on_children_changed<T: Kind, M>(
+        &mut self,
+        system: impl IntoSystem<In<Instance<View<T>>>, (), M> + 'static,
+    ) -> &mut Self;
+
+    /// Invokes the given callback whenever [`Component`] `S` of a viewable of [`Kind`] `T` is changed, with its view.
+    ///
+    /// This is intended for discrete states, such as an enum which selects the animation or mesh of a view.
+    /// The callback is also invoked for the initial state of each new view, and is never invoked twice in a row
+    /// for equal states of the same view. Changes are detected in [`PostUpdate`], so changes made during [`Update`]
+    /// are synchronized in the same update. Viewables without `S` are ignored.
+    ///
+    /// Only one callback may be added for each combination of `T` and `S`. Any further call is ignored
+    /// with a warning, and the first callback is kept.
+    fn add_view_state_sync<T: Kind, S: Component + PartialEq + Clone>(
+        &mut self,
+        on_change: impl Fn(&S, Instance<View<T>>, &mut Commands) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Spawns children of views of [`Kind`] `T` based on the level of detail `L` of their viewable entity.
+    ///
+    /// The given function is called to spawn the children of each new view with its current level of detail,
+    /// and again whenever `L` changes to a different value. Before it is called again, all children spawned by
+    /// the previous call are despawned. Other children of the view are not affected. This runs in [`PostUpdate`].
+    ///
+    /// Only one level of detail function may be added for each combination of `T` and `L`. Any further call is
+    /// ignored with a warning, and the first function is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Unit;
+    ///
+    /// impl BuildView for Unit {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component, PartialEq, Clone)]
+    /// struct Lod(u8);
+    ///
+    /// #[derive(Component)]
+    /// struct Mesh;
+    ///
+    /// #[derive(Component)]
+    /// struct Impostor;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(MinimalPlugins)
+    ///     .add_viewable::<Unit>()
+    ///     .add_view_lod::<Unit, Lod>(|lod, parent| match lod.0 {
+    ///         0 => {
+    ///             parent.spawn(Mesh);
+    ///         }
+    ///         _ => {
+    ///             parent.spawn(Impostor);
+    ///         }
+    ///     });
+    ///
+    /// let unit = app.world_mut().spawn((Unit, Lod(0))).id();
+    /// app.update();
+    /// let view = app.world().get::<Viewable<Unit>>(unit).unwrap().view().entity();
+    /// let child = app.world().get::<Children>(view).unwrap()[0];
+    /// assert!(app.world().get::<Mesh>(child).is_some());
+    ///
+    /// app.world_mut().get_mut::<Lod>(unit).unwrap().0 = 1;
+    /// app.update();
+    /// let child = app.world().get::<Children>(view).unwrap()[0];
+    /// assert!(app.world().get::<Impostor>(child).is_some());
+    /// ```
+    fn add_view_lod<T: Kind, L: Component + PartialEq + Clone>(
+        &mut self,
+        spawn: impl Fn(&L, &mut WorldChildBuilder) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Writes [`Component`] `C` onto viewables of [`Kind`] `T` whenever [`Component`] `D` of their view is changed.
+    ///
+    /// This is the reverse of the usual synchronization, and is useful when the view is manipulated directly,
+    /// such as when dragging a view in an editor. Changes are written back in [`PostUpdate`].
+    ///
+    /// If `C` is also synchronized from the viewable to the view, the last writer wins. To avoid feedback
+    /// loops, `C` is only changed if the new value differs from the current one.
+    ///
+    /// Only one writeback may be added for each combination of `T`, `D` and `C`. Any further call is ignored
+    /// with a warning, and the first function is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Bird;
+    ///
+    /// #[derive(Component, PartialEq)]
+    /// struct Position(Vec2);
+    ///
+    /// impl BuildView for Bird {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_viewable::<Bird>()
+    ///     .add_view_writeback::<Bird, Transform, Position>(|transform| {
+    ///         Position(transform.translation.truncate())
+    ///     });
+    /// ```
+    fn add_view_writeback<T: Kind, D: Component, C: Component + PartialEq>(
+        &mut self,
+        f: impl Fn(&D) -> C + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Clones the given components from each viewable entity of [`Kind`] `T` into its view, using reflection.
+    ///
+    /// Components are cloned when the view is spawned, and again in [`PostUpdate`] whenever they change.
+    /// Each component must be registered in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) with
+    /// `#[reflect(Component)]`. Any component which is not reflectable is skipped with a warning.
+    ///
+    /// This is intended for prototyping and tooling, and is slower than synchronizing components manually.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Bird;
+    ///
+    /// #[derive(Component, Reflect, Clone)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// impl BuildView for Bird {
+    ///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.register_type::<Health>().add_viewable::<Bird>();
+    /// let health = app.world_mut().register_component::<Health>();
+    /// app.add_view_reflect_mirror::<Bird>(&[health]);
+    /// ```
+    #[cfg(feature = "reflect")]
+    fn add_view_reflect_mirror<T: Kind>(&mut self, components: &[ComponentId]) -> &mut Self;
+
+    /// Adds a callback which is invoked whenever a view of [`Kind`] `T` is despawned.
+    ///
+    /// The callback is invoked before any of the view's components are removed, so it may still access them.
+    /// Unlike [`on_despawn`], which observes a single view, this callback is invoked for every view of `T`.
+    fn add_view_on_despawn<T: Kind>(
+        &mut self,
+        callback: impl Fn(Instance<View<T>>, &mut DeferredWorld) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Pauses all view systems. See [`ViewsPaused`] for details.
+    fn pause_views(&mut self) -> &mut Self;
+
+    /// Resumes all view systems after [`pause_views`](RegisterView::pause_views).
+    fn resume_views(&mut self) -> &mut Self;
+
+    #[deprecated(note = "Use `add_view` instead")]
+    fn register_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self {
+        self.add_view::<T, V>()
+    }
+
+    #[deprecated(note = "Use `add_viewable` instead")]
+    fn register_viewable<T: BuildView>(&mut self) -> &mut Self {
+        self.add_viewable::<T>()
+    }
+}
+
+impl RegisterView for App {
+    fn add_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self {
+        add_view_kind::<T>(self);
+        self.add_systems(
+            PreUpdate,
+            build_view::<T, V>
+                .in_set(ViewSystems::Build)
+                .in_set(BuildViewKind(TypeId::of::<T>())),
+        );
+        if TypeId::of::<T>() != TypeId::of::<V>() {
+            self.add_systems(
+                Last,
+                retain_view::<T, V>
+                    .in_set(ViewSystems::Despawn)
+                    .before(despawn_view::<T>),
+            );
+            self.world_mut()
+                .get_resource_or_insert_with(ViewVariants::<T>::default)
+                .retargets
+                .push(retarget_variant::<T, V>);
+        }
+        self
+    }
+
+    fn view_kind<T: Kind>(&mut self) -> ViewKindBuilder<'_, T> {
+        ViewKindBuilder::new(self)
+    }
+
+    fn add_view_component<C: Component>(&mut self) -> &mut Self {
+        add_view_kind::<C>(self);
+        self
+    }
+
+    fn add_view_priority<T: Kind>(&mut self, priority: i32) -> &mut Self {
+        self.configure_sets(
+            PreUpdate,
+            BuildViewKind(TypeId::of::<T>()).in_set(BuildViewPriority(priority)),
+        );
+        let mut priorities = self
+            .world_mut()
+            .get_resource_or_insert_with(ViewPriorities::default);
+        if !priorities.priorities.insert(priority) {
+            return self;
+        }
+        let before = priorities.priorities.range(..priority).next_back().copied();
+        let after = priorities.priorities.range(priority + 1..).next().copied();
+        self.configure_sets(
+            PreUpdate,
+            BuildViewPriority(priority).in_set(ViewSystems::Build),
+        );
+        if let Some(before) = before {
+            self.configure_sets(
+                PreUpdate,
+                BuildViewPriority(before).before(BuildViewPriority(priority)),
+            );
+        }
+        if let Some(after) = after {
+            self.configure_sets(
+                PreUpdate,
+                BuildViewPriority(priority).before(BuildViewPriority(after)),
+            );
+        }
+        self
+    }
+
+    fn add_view_fallback<T: Kind, F: BuildView<T>>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            build_view_fallback::<T, F>
+                .after(ViewSystems::Build)
+                .in_set(PostBuildView)
+                .before(clear_retargeted::<T>)
+                .run_if(views_active),
+        )
+    }
+
+    fn add_view_hierarchy<T: Kind>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            hierarchy::mirror_view_hierarchy::<T>
+                .after(spawn_view::<T>)
+                .run_if(views_active),
+        )
+    }
+
+    fn add_view_root<T: Kind>(&mut self, root: Entity) -> &mut Self {
+        self.insert_resource(hierarchy::ViewRoot::<T>::new(root))
+            .add_systems(
+                PreUpdate,
+                hierarchy::parent_view_root::<T>
+                    .after(ViewSystems::Spawn)
+                    .before(ViewSystems::Build)
+                    .before(hierarchy::mirror_view_hierarchy::<T>)
+                    .run_if(views_active),
+            )
+    }
+
+    #[cfg(feature = "transform")]
+    fn add_view_transform<T: ViewTransform>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            transform::insert_view_transform::<T>
+                .after(ViewSystems::Spawn)
+                .before(ViewSystems::Build)
+                .run_if(views_active),
+        )
+    }
+
+    #[cfg(feature = "transform")]
+    fn add_view_offset_transform<T: Kind>(
+        &mut self,
+        offset: bevy_transform::prelude::Transform,
+    ) -> &mut Self {
+        if self.world().contains_resource::<transform::ViewOffset<T>>() {
+            warn!(
+                "offset transform of {} is already added and is ignored",
+                T::debug_name()
+            );
+            return self;
+        }
+        sync::register_sync_system::<T, _>(
+            self.world_mut(),
+            transform::sync_view_offset_transform::<T>,
+        );
+        self.insert_resource(transform::ViewOffset::<T>::new(offset))
+            .add_systems(
+                PostUpdate,
+                transform::sync_view_offset_transform::<T>
+                    .before(bevy_transform::TransformSystem::TransformPropagate)
+                    .run_if(views_active),
+            )
+    }
+
+    #[cfg(feature = "transform")]
+    fn add_view_interpolated_transform<T: Kind>(&mut self, snap: f32) -> &mut Self {
+        self.insert_resource(transform::ViewInterpolation::<T>::new(snap))
+            .add_systems(
+                FixedPostUpdate,
+                transform::record_view_transform::<T>.run_if(views_active),
+            )
+            .add_systems(
+                PostUpdate,
+                transform::interpolate_view_transform::<T>
+                    .before(bevy_transform::TransformSystem::TransformPropagate)
+                    .run_if(views_active),
+            )
+    }
+
+    #[cfg(feature = "render")]
+    fn add_view_spatial<T: Kind>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            spatial::insert_view_spatial::<T>
+                .after(ViewSystems::Build)
+                .in_set(PostBuildView)
+                .run_if(views_active),
+        )
+    }
+
+    #[cfg(feature = "gizmos")]
+    fn add_view_gizmos<T: Kind>(&mut self) -> &mut Self {
+        self.init_resource::<ViewGizmos>().add_systems(
+            PostUpdate,
+            gizmos::draw_view_gizmos::<T>
+                .after(bevy_transform::TransformSystem::TransformPropagate)
+                .run_if(resource_exists::<bevy_gizmos::config::GizmoConfigStore>),
+        )
+    }
+
+    fn add_view_change_event<T: Kind, C: Component>(&mut self) -> &mut Self {
+        self.add_event::<ViewComponentChanged<T, C>>().add_systems(
+            PostUpdate,
+            change::send_view_changes::<T, C>.run_if(views_active),
+        )
+    }
+
+    #[cfg(feature = "asset")]
+    fn add_view_rebuild_on_asset<T: Kind, A: bevy_asset::Asset>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            asset::rebuild_views_on_asset::<T, A>
+                .before(ViewSystems::Spawn)
+                .run_if(views_active)
+                .run_if(resource_exists::<Events<bevy_asset::AssetEvent<A>>>),
+        )
+    }
+
+    fn add_view_rebuild_on_change<T: Kind, C: Component>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            change::rebuild_views_on_change::<T, C>
+                .before(ViewSystems::Spawn)
+                .run_if(views_active),
+        )
+    }
+
+    fn add_view_rebuild_on_key<T: ViewRebuildKey, C: Component>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            (
+                change::rebuild_views_on_key::<T, C>.before(ViewSystems::Spawn),
+                change::record_view_rebuild_key::<T>
+                    .after(ViewSystems::Build)
+                    .after(PostBuildView),
+            )
+                .run_if(views_active),
+        )
+    }
+
+    fn add_view_rebuild_on<T: Kind, C: Component>(&mut self) -> &mut Self {
+        self.init_resource::<change::PendingViewRebuilds<T, C>>()
+            .add_observer(change::request_view_rebuild::<T, C, OnAdd>)
+            .add_observer(change::request_view_rebuild::<T, C, OnRemove>)
+            .add_systems(
+                PreUpdate,
+                change::rebuild_pending_views::<T, C>
+                    .before(ViewSystems::Spawn)
+                    .run_if(views_active),
+            )
+    }
+
+    fn add_view_inactive<T: Kind>(&mut self) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            inactive::insert_view_inactive::<T>
+                .after(ViewSystems::Build)
+                .after(PostBuildView)
+                .run_if(views_active),
+        )
+    }
+
+    #[cfg(feature = "transition")]
+    fn add_view_transitions<T: Kind>(
+        &mut self,
+        appear: Duration,
+        disappear: Duration,
+    ) -> &mut Self {
+        self.insert_resource(transition::ViewTransitions::<T>::new(appear, disappear))
+            .add_systems(
+                PreUpdate,
+                transition::insert_view_appearing::<T>
+                    .after(ViewSystems::Spawn)
+                    .before(ViewSystems::Build)
+                    .run_if(views_active),
+            )
+    }
+
+    fn add_view_debounce<T: Kind>(&mut self, frames: u32) -> &mut Self {
+        self.insert_resource(debounce::ViewDebounce::<T>::new(frames))
+            .add_systems(
+                PreUpdate,
+                debounce::debounce_views::<T>
+                    .before(ViewSystems::Spawn)
+                    .run_if(views_active),
+            )
+    }
+
+    fn add_view_time_budget<T: Kind>(&mut self, budget: Duration) -> &mut Self {
+        self.insert_resource(budget::ViewTimeBudget::<T>::new(budget))
+    }
+
+    fn add_view_reactive<T: Kind, C: Component>(&mut self) -> &mut Self {
+        self.init_resource::<ManualViewSpawn<T>>()
+            .add_observer(spawn_view_on_add::<T, C>)
+    }
+
+    fn add_view_lazy<T: Kind>(&mut self) -> &mut Self {
+        self.init_resource::<ManualViewSpawn<T>>()
+    }
+
+    fn add_view_veto<T: Kind>(
+        &mut self,
+        veto: impl Fn(&World, Instance<T>) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(veto::ViewVetoes::<T>::default)
+            .add(veto);
+        self
+    }
+
+    fn add_view_settled<T: Kind>(&mut self) -> &mut Self {
+        self.add_view_veto::<T>(settle::is_unsettled::<T>)
+            .add_systems(Last, settle::settle_views::<T>)
+    }
+
+    #[cfg(feature = "state")]
+    fn add_view_in_state<T: Kind, S: bevy_state::state::States>(&mut self, state: S) -> &mut Self {
+        let exit = bevy_state::state::OnExit(state.clone());
+        self.add_view_veto::<T>(move |world, _| state::is_not_in_state(world, &state))
+            .add_systems(exit, state::despawn_views::<T>)
+    }
+
+    fn add_view_finder<T: Kind>(
+        &mut self,
+        finder: impl Fn(&World, Instance<T>) -> Option<Entity> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(find::ViewFinders::<T>::default)
+            .add(finder);
+        self
+    }
+
+    #[cfg(feature = "save")]
+    fn add_view_saved<T: Kind + TypePath>(&mut self) -> &mut Self {
+        self.register_type::<View<T>>()
+            .register_type::<Viewable<T>>()
+            .register_type::<IsView>()
+            .register_type::<IsViewable>()
+            .add_systems(
+                PreUpdate,
+                (
+                    save::restore_saved_views::<T>
+                        .after(LoadSystem::PostLoad)
+                        .before(ViewSystems::Spawn),
+                    save::insert_view_save::<T>
+                        .after(ViewSystems::Spawn)
+                        .before(ViewSystems::Build),
+                )
+                    .run_if(views_active),
+            )
+    }
+
+    fn add_view_allocator<T: Kind>(
+        &mut self,
+        allocator: impl FnMut(&mut World) -> Entity + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.insert_resource(find::ViewAllocator::<T>::new(allocator))
+    }
+
+    fn add_shared_viewable<T: Kind, K: Eq + std::hash::Hash + Send + Sync + 'static>(
+        &mut self,
+        key: impl Fn(&World, Instance<T>) -> K + Send + Sync + 'static,
+    ) -> &mut Self {
+        init_views(self);
+        self.insert_resource(shared::SharedViews::<T, K>::new(key))
+            .add_systems(
+                PreUpdate,
+                shared::assign_shared_views::<T, K>
+                    .in_set(ViewSystems::Spawn)
+                    .run_if(views_active),
+            )
+            .add_systems(
+                Last,
+                shared::retain_shared_views::<T, K>.in_set(ViewSystems::Despawn),
+            )
+    }
+
+    fn add_view_linked<T: Kind>(&mut self, linked: bool) -> &mut Self {
+        self.add_event::<ViewOrphaned<T>>()
+            .insert_resource(ViewLinked::<T> {
+                linked,
+                orphans: Vec::new(),
+                marker: PhantomData,
+            })
+    }
+
+    fn add_view_preserve<T: Kind, C: Component + Clone>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(preserve::ViewPreservers::<T>::default)
+            .add::<C>();
+        self.add_systems(
+            PreUpdate,
+            preserve::restore_preserved::<T, C>
+                .after(ViewSystems::Build)
+                .in_set(PostBuildView)
+                .run_if(views_active),
+        )
+    }
+
+    fn add_view_build_system<T: Kind, M>(
+        &mut self,
+        system: impl IntoSystem<In<Instance<View<T>>>, (), M> + 'static,
+    ) -> &mut Self {
+        let system = self.world_mut().register_system(system);
+        self.add_systems(
+            PreUpdate,
+            (move |query: Query<&Viewable<T>, Added<Viewable<T>>>, mut commands: Commands| {
+                for viewable in query.iter() {
+                    commands.run_system_with_input(system, viewable.view());
+                }
+            })
+            .after(ViewSystems::Build)
+            .in_set(PostBuildView)
+            .run_if(views_active),
+        )
+    }
+
+    fn add_view_finalizer<T: Kind, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.add_systems(
+            PreUpdate,
+            system
+                .after(ViewSystems::Build)
+                .after(PostBuildView)
+                .run_if(views_active)
+                .run_if(|views: NewViews<T>| !views.is_empty()),
+        )
+    }
+
+    fn add_view_writeback<T: Kind, D: Component, C: Component + PartialEq>(
+        &mut self,
+        f: impl Fn(&D) -> C + Send + Sync + 'static,
+    ) -> &mut Self {
+        if self
+            .world()
+            .contains_resource::<writeback::ViewWriteback<T, D, C>>()
+        {
+            warn!(
+                "writeback of {} from {} to {} is already added and is ignored",
+                T::debug_name(),
+                std::any::type_name::<D>(),
+                std::any::type_name::<C>()
+            );
+            return self;
+        }
+        self.insert_resource(writeback::ViewWriteback::<T, D, C>::new(f))
+            .add_systems(
+                PostUpdate,
+                writeback::write_back_views::<T, D, C>.run_if(views_active),
+            )
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn add_view_on_children_changed<T: Kind, M>(
+        &mut self,
+        system: impl IntoSystem<In<Instance<View<T>>>, (), M> + 'static,
+    ) -> &mut Self {
+        let system = self.world_mut().register_system(system);
+        self.add_systems(
+            PostUpdate,
+            (move |changed: Query<Entity, (With<View<T>>, Changed<Children>)>,
+                   mut removed: RemovedComponents<Children>,
+                   views: Query<(Instance<View<T>>, Ref<View<T>>)>,
+                   mut commands: Commands| {
+                for entity in changed.iter().chain(removed.read()) {
+                    let Ok((view, view_ref)) = views.get(entity) else {
+                        continue;
+                    };
+                    if view_ref.is_added() {
+                        continue;
+                    }
+                    commands.queue(move |world: &mut World| {
+                        if world.get_entity(view.entity()).is_ok() {
+                            let _ = world.run_system_with_input(system, view);
+                        }
+                    });
+                }
+            })
+            .run_if(views_active),
+        )
+    }
+
+    fn add_view_state_sync<T: Kind, S: Component + PartialEq + Clone>(
+        &mut self,
+        on_change: impl Fn(&S, Instance<View<T>>, &mut Commands) + Send + Sync + 'static,
+    ) -> &mut Self {
+        if self
+            .world()
+            .contains_resource::<sync::ViewStateSync<T, S>>()
+        {
+            warn!(
+                "state sync of {} from {} is already added and is ignored",
+                T::debug_name(),
+                std::any::type_name::<S>()
+            );
+            return self;
+        }
+        sync::register_sync_system::<T, _>(self.world_mut(), sync::sync_view_state::<T, S>);
+        self.insert_resource(sync::ViewStateSync::<T, S>::new(on_change))
+            .add_systems(
+                PostUpdate,
+                sync::sync_view_state::<T, S>.run_if(views_active),
+            )
+    }
+
+    fn add_view_lod<T: Kind, L: Component + PartialEq + Clone>(
+        &mut self,
+        spawn: impl Fn(&L, &mut WorldChildBuilder) + Send + Sync + 'static,
+    ) -> &mut Self {
+        if self.world().contains_resource::<lod::ViewLod<T, L>>() {
+            warn!(
+                "level of detail {} of {} is already added and is ignored",
+                std::any::type_name::<L>(),
+                T::debug_name()
+            );
+            return self;
+        }
+        self.insert_resource(lod::ViewLod::<T, L>::new(spawn))
+            .add_systems(
+                PostUpdate,
+                lod::update_view_lod::<T, L>.run_if(views_active),
+            )
+    }
+
+    #[cfg(feature = "reflect")]
+    fn add_view_reflect_mirror<T: Kind>(&mut self, components: &[ComponentId]) -> &mut Self {
+        sync::register_sync_system::<T, _>(self.world_mut(), reflect::mirror_reflected_views::<T>);
+        self.insert_resource(reflect::ViewReflectMirror::<T>::new(components))
+            .add_systems(
+                PostUpdate,
+                reflect::mirror_reflected_views::<T>.run_if(views_active),
+            )
+    }
+
+    fn add_view_on_despawn<T: Kind>(
+        &mut self,
+        callback: impl Fn(Instance<View<T>>, &mut DeferredWorld) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(ViewDespawnCallbacks::<T>::default)
+            .callbacks
+            .push(Arc::new(callback));
+        self
+    }
+
+    fn pause_views(&mut self) -> &mut Self {
+        self.insert_resource(ViewsPaused(true))
+    }
+
+    fn resume_views(&mut self) -> &mut Self {
+        self.insert_resource(ViewsPaused(false))
+    }
+}
+
+/// System sets used by the view systems of all registered viewable kinds.
+///
+/// Views are spawned after [`LoadSystem::PostLoad`], so that views of loaded entities are built in the same update
+/// they're loaded, and only once.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ViewSystems {
+    /// Views are spawned for new viewable entities in [`PreUpdate`].
+    Spawn,
+    /// Views are built by each [`BuildView`] implementation in [`PreUpdate`], after [`ViewSystems::Spawn`].
+    Build,
+    /// Views of entities which are no longer viewable are despawned in [`Last`].
+    Despawn,
+}
+
+/// Configures [`ViewSystems`] and inserts [`Viewables`], if not already done.
+fn init_views(app: &mut App) {
+    if app.world().contains_resource::<Viewables>() {
+        return;
+    }
+    app.configure_sets(
+        PreUpdate,
+        (ViewSystems::Spawn, ViewSystems::Build)
+            .chain()
+            .after(CheckSystems)
+            .after(LoadSystem::PostLoad)
+            .run_if(views_active),
+    );
+    app.configure_sets(Last, ViewSystems::Despawn.run_if(views_active));
+    app.init_resource::<Viewables>();
+}
+
+/// Registers the systems of a viewable [`Kind`] `T`, except for its build systems, if not already registered.
+fn add_view_kind<T: Kind>(app: &mut App) {
+    init_views(app);
+    let mut viewables = app.world_mut().resource_mut::<Viewables>();
+    if !viewables.is_viewable_kind::<T>() {
+        viewables.add_kind::<T>();
+        app.init_resource::<DespawnedViews<T>>()
+            .add_systems(First, metrics::clear_despawned_views::<T>);
+        #[cfg(feature = "metrics")]
+        app.init_resource::<ViewMetrics<T>>()
+            .add_event::<NoViewsRemaining<T>>();
+        app.world_mut()
+            .register_component_hooks::<View<T>>()
+            .on_remove(on_remove_view::<T>);
+        app.world_mut()
+            .register_component_hooks::<Viewable<T>>()
+            .on_insert(on_insert_viewable)
+            .on_remove(on_remove_viewable);
+        app.add_systems(
+            PreUpdate,
+            spawn_view::<T>
+                .in_set(ViewSystems::Spawn)
+                .run_if(not(resource_exists::<ManualViewSpawn<T>>)),
+        );
+        app.add_systems(
+            PreUpdate,
+            clear_retargeted::<T>
+                .after(ViewSystems::Build)
+                .run_if(views_active),
+        );
+        app.add_systems(Last, despawn_view::<T>.in_set(ViewSystems::Despawn));
+        #[cfg(feature = "transition")]
+        app.add_systems(
+            Last,
+            transition::tick_view_transitions::<T>
+                .in_set(ViewSystems::Despawn)
+                .after(despawn_view::<T>)
+                .run_if(resource_exists::<bevy_time::Time>),
+        );
+        #[cfg(feature = "debug")]
+        app.add_systems(
+            Last,
+            (
+                diagnostics::warn_orphan_views::<T>,
+                diagnostics::check_view_invariants::<T>,
+            )
+                .after(despawn_view::<T>)
+                .run_if(views_active),
+        );
+    }
+}
+
+/// A [`SystemSet`] which contains systems which run after [`ViewSystems::Build`] to complete new views,
+/// such as fallbacks and build systems.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct PostBuildView;
+
+/// A [`SystemSet`] which contains the build systems of a single viewable kind.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct BuildViewKind(TypeId);
+
+/// A [`SystemSet`] which contains the build systems of all viewable kinds with the same priority.
+///
+/// See [`add_view_priority`](RegisterView::add_view_priority) for details.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct BuildViewPriority(i32);
+
+/// A [`Resource`] which stores all priorities set using [`add_view_priority`](RegisterView::add_view_priority).
+#[derive(Resource, Default)]
+struct ViewPriorities {
+    priorities: BTreeSet<i32>,
+}
+
+/// A [`Resource`] which pauses all view systems of all viewable kinds while set to `true`.
+///
+/// While paused, no views are spawned, built, despawned or synchronized by this crate. Any changes made while
+/// paused are handled once the view systems are resumed. This is useful for stepping through updates while debugging.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewsPaused(pub bool);
+
+fn views_active(paused: Option<Res<ViewsPaused>>) -> bool {
+    !paused.is_some_and(|paused| paused.0)
+}
+
+/// Extension trait used to inspect and manage views using a [`World`].
+pub trait ViewWorld {
+    /// Returns the number of entities with a [`Viewable<T>`].
+    fn viewable_count<T: Kind>(&self) -> usize;
+
+    /// Returns the number of entities with a [`View<T>`].
+    fn view_count<T: Kind>(&self) -> usize;
+
+    /// Returns the [`View<T>`] of the given viewable [`Entity`], if it has one.
+    ///
+    /// This is a constant time lookup, equivalent to `Query<&Viewable<T>>::get`.
+    /// There is no need to build a separate index of views for fast lookups.
+    fn view<T: Kind>(&self, viewable: Entity) -> Option<Instance<View<T>>>;
+
+    /// Returns `true` if the given viewable [`Entity`] has a [`View<T>`].
+    ///
+    /// Returns `false` if the entity does not exist. Within systems, use [`Viewables::has_view`] instead.
+    fn has_view<T: Kind>(&self, viewable: Entity) -> bool;
+
+    /// Returns the viewable [`Instance`] of each of the given view entities, in the same order.
+    ///
+    /// Each entity which does not exist, or is not a [`View<T>`], is mapped to `None`.
+    /// This is useful to map views collected in bulk, such as from a picking or render pass, back to their viewables.
+    fn viewables_of_views<T: Kind>(&self, views: &[Entity]) -> Vec<Option<Instance<T>>>;
+
+    /// Returns `true` if the given [`Entity`] is a view of any viewable kind.
+    ///
+    /// This is useful for kind-agnostic systems, such as selection, which need to classify arbitrary entities.
+    /// Any entity marked with [`IsView`] is a view, including [shared views](SharedView) and [previews](PreviewView).
+    fn is_view(&self, entity: Entity) -> bool;
+
+    /// Returns `true` if the given [`Entity`] has a view of any viewable kind.
+    ///
+    /// Any entity marked with [`IsViewable`] is viewable.
+    fn is_viewable(&self, entity: Entity) -> bool;
+
+    /// Returns the number of viewable instances of [`Kind`] `T` which are waiting for a view to be spawned.
+    ///
+    /// This is useful to show loading progress while views are throttled, such as with
+    /// [`add_view_time_budget`](RegisterView::add_view_time_budget). [Dormant](ViewDormant) instances are excluded.
+    fn build_backlog<T: Kind>(&mut self) -> usize;
+
+    /// Returns the sum of [`build_backlog`](ViewWorld::build_backlog) of all registered viewable kinds.
+    fn total_build_backlog(&mut self) -> usize;
+
+    /// Runs the given closure, and keeps the views of any viewable entities of [`Kind`] `T` despawned within it.
+    ///
+    /// Views of viewables despawned within the closure are unlinked from them, as if the kind was
+    /// [not linked](RegisterView::add_view_linked): their [`View<T>`] is removed, and [`ViewOrphaned<T>`] is sent
+    /// if the event is registered. The views are then left for the caller to manage, such as to adopt them with
+    /// [`adopt`] during a complex migration. Views of viewables despawned outside of the closure are unaffected.
+    fn suppress_view_despawn<T: Kind, R>(&mut self, f: impl FnOnce(&mut World) -> R) -> R;
+
+    /// Runs the systems which synchronize views of [`Kind`] `T` with their viewables immediately.
+    ///
+    /// Views are normally synchronized in [`PostUpdate`] when their viewables change. This is useful right
+    /// after a bulk change to viewables, such as after a load, to avoid a frame of visual lag. The systems added
+    /// by [`add_view_offset_transform`](RegisterView::add_view_offset_transform),
+    /// [`add_view_state_sync`](RegisterView::add_view_state_sync) and
+    /// [`add_view_reflect_mirror`](RegisterView::add_view_reflect_mirror) are run once each, and only consider
+    /// changes since they were last run using this method.
+    ///
+    /// These are one-shot copies of the systems which run in [`PostUpdate`], and keep their own change ticks.
+    /// So the first call considers every viewable as changed, regardless of any earlier updates. This is safe,
+    /// since each system skips views which are already in sync, but may be slower than later calls.
+    ///
+    /// Transform propagation is not run, so the `GlobalTransform` of views is only updated during the next update.
+    fn sync_views_now<T: Kind>(&mut self);
+
+    /// Despawns all views of all viewable kinds immediately, and keeps their viewable entities.
+    ///
+    /// This is useful for state transitions which tear down all views at once. Views are despawned without
+    /// any [transitions](RegisterView::add_view_transitions), and new views are spawned for any viewable
+    /// entities during the next update, unless view systems are [paused](ViewsPaused).
+    /// [Preview](PreviewView) and orphaned views are not despawned, since they are not associated with any viewable.
+    fn despawn_all_views(&mut self);
+
+    /// Returns all orphaned views of [`Kind`] `T`.
+    ///
+    /// A view is orphaned if its viewable entity is despawned, but the view is not. This includes views which
+    /// are [disappearing](ViewDisappearing), or which are [not linked](RegisterView::add_view_linked) to their
+    /// viewable and have not been despawned or [adopted](adopt) since. [Preview](PreviewView) views are excluded.
+    ///
+    /// This is useful for editor tooling which cleans up stale views.
+    fn orphaned_views<T: Kind>(&self) -> Vec<Entity>;
+
+    /// Despawns all [orphaned views](ViewWorld::orphaned_views) of [`Kind`] `T` immediately.
+    fn despawn_orphaned_views<T: Kind>(&mut self);
+}
+
+impl ViewWorld for World {
+    fn viewable_count<T: Kind>(&self) -> usize {
+        component_count::<Viewable<T>>(self)
+    }
+
+    fn view_count<T: Kind>(&self) -> usize {
+        component_count::<View<T>>(self)
+    }
+
+    fn view<T: Kind>(&self, viewable: Entity) -> Option<Instance<View<T>>> {
+        self.get_entity(viewable)
+            .ok()?
+            .get::<Viewable<T>>()
+            .map(Viewable::view)
+    }
+
+    fn has_view<T: Kind>(&self, viewable: Entity) -> bool {
+        self.get_entity(viewable)
+            .is_ok_and(|entity| entity.contains::<Viewable<T>>())
+    }
+
+    fn viewables_of_views<T: Kind>(&self, views: &[Entity]) -> Vec<Option<Instance<T>>> {
+        views
+            .iter()
+            .map(|&view| {
+                self.get_entity(view)
+                    .ok()?
+                    .get::<View<T>>()
+                    .map(View::viewable)
+            })
+            .collect()
+    }
+
+    fn is_view(&self, entity: Entity) -> bool {
+        self.get_entity(entity)
+            .is_ok_and(|entity| entity.contains::<IsView>())
+    }
+
+    fn is_viewable(&self, entity: Entity) -> bool {
+        self.get_entity(entity)
+            .is_ok_and(|entity| entity.contains::<IsViewable>())
+    }
+
+    fn orphaned_views<T: Kind>(&self) -> Vec<Entity> {
+        let mut orphans: Vec<Entity> = self
+            .iter_entities()
+            .filter(|entity| !entity.contains::<PreviewView>())
+            .filter(|entity| {
+                entity
+                    .get::<View<T>>()
+                    .is_some_and(|view| self.get_entity(view.viewable().entity()).is_err())
+            })
+            .map(|entity| entity.id())
+            .collect();
+        if let Some(linked) = self.get_resource::<ViewLinked<T>>() {
+            orphans.extend(linked.orphans.iter().copied().filter(|&orphan| {
+                self.get_entity(orphan)
+                    .is_ok_and(|entity| !entity.contains::<View<T>>())
+            }));
+        }
+        orphans
+    }
+
+    fn despawn_orphaned_views<T: Kind>(&mut self) {
+        for orphan in self.orphaned_views::<T>() {
+            despawn_view_recursive(self, orphan);
+        }
+        if let Some(mut linked) = self.get_resource_mut::<ViewLinked<T>>() {
+            linked.orphans.clear();
+        }
+        self.flush();
+    }
+
+    fn build_backlog<T: Kind>(&mut self) -> usize {
+        build_backlog::<T>(self)
+    }
+
+    fn sync_views_now<T: Kind>(&mut self) {
+        let Some(systems) = self.get_resource::<sync::ViewSyncSystems<T>>() else {
+            return;
+        };
+        for system in systems.systems().to_vec() {
+            if let Err(error) = self.run_system(system) {
+                warn!("failed to sync views of {}: {error}", T::debug_name());
+            }
+        }
+    }
+
+    fn total_build_backlog(&mut self) -> usize {
+        let Some(viewables) = self.get_resource::<Viewables>() else {
+            return 0;
+        };
+        let backlogs = viewables.backlogs.clone();
+        backlogs.into_iter().map(|backlog| backlog(self)).sum()
+    }
+
+    fn suppress_view_despawn<T: Kind, R>(&mut self, f: impl FnOnce(&mut World) -> R) -> R {
+        let views: Vec<(Instance<T>, Instance<View<T>>)> = self
+            .query_filtered::<InstanceRef<View<T>>, Without<PreviewView>>()
+            .iter(self)
+            .map(|view| (view.viewable(), view.instance()))
+            .collect();
+        let result = f(self);
+        for (viewable, view) in views {
+            let despawned = self.get_entity(viewable.entity()).is_err();
+            if despawned && self.get::<View<T>>(view.entity()).is_some() {
+                unlink_view(self, viewable, view);
+            }
+        }
+        self.flush();
+        result
+    }
+
+    fn despawn_all_views(&mut self) {
+        let Some(viewables) = self.get_resource::<Viewables>() else {
+            return;
+        };
+        let views: Vec<Entity> = viewables.views.keys().copied().collect();
+        for view in views {
+            despawn_view_recursive(self, view);
+        }
+        self.flush();
+    }
+}
+
+fn build_backlog<T: Kind>(world: &mut World) -> usize {
+    world
+        .query_filtered::<(), (T::Filter, Without<Viewable<T>>, Without<ViewDormant<T>>)>()
+        .iter(world)
+        .count()
+}
+
+fn component_count<T: Component>(world: &World) -> usize {
+    let Some(component) = world.component_id::<T>() else {
+        return 0;
+    };
+    world
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(component))
+        .map(|archetype| archetype.len())
+        .sum()
+}
+
+/// Trait used to spawn a [`View`] [`Entity`] for an [`Instance`] of [`Kind`] `T`.
+///
+/// The view is built with read-only access to the [`World`], so any component of the viewable entity may be
+/// used to configure its view. This allows build parameters to be stored on the viewable itself, with a
+/// default for viewables which have none.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// #[derive(Component, Clone, Copy)]
+/// struct BirdConfig {
+///     scale: f32,
+/// }
+///
+/// impl Default for BirdConfig {
+///     fn default() -> Self {
+///         Self { scale: 1.0 }
+///     }
+/// }
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+///         let config = world.get::<BirdConfig>(object.entity()).copied().unwrap_or_default();
+///         view.insert(Transform::from_scale(Vec3::splat(config.scale)));
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(|mut commands: Commands| {
+/// commands.spawn((Bird, BirdConfig { scale: 2.0 }));
+/// # });
+/// ```
+pub trait BuildView<T: Kind = Self>: Kind {
+    /// Called when a new [`Instance`] of [`Kind`] `T` is spawned without a [`View`].
+    ///
+    /// Remember to register this type using [`RegisterView`] for this to happen.
+    ///
+    /// The [`EntityRef`] of the viewable may be used to branch on its components, so that all variants
+    /// of a kind are built in one place:
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_core::prelude::*;
+    /// # use moonshine_view::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Shape;
+    ///
+    /// #[derive(Component)]
+    /// struct Square;
+    ///
+    /// #[derive(Component)]
+    /// struct Circle;
+    ///
+    /// impl BuildView for Shape {
+    ///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+    ///         let entity = world.entity(object.entity());
+    ///         view.with_children(|view| {
+    ///             if entity.contains::<Square>() {
+    ///                 // view.spawn(...);
+    ///             } else if entity.contains::<Circle>() {
+    ///                 // view.spawn(...);
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    fn build(_world: &World, _object: Object<T>, view: ViewCommands<T>);
+}
+
+pub type ViewCommands<'a, T> = InstanceCommands<'a, View<T>>;
+
+/// [`Component`] of an [`Entity`] associated with a [`View`].
+///
+/// A [`Viewable`] is compared and hashed by its [`View`] entity, so it may be used as a key in collections.
+#[derive(Component)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component, MapEntities))]
+pub struct Viewable<T: Kind> {
+    view: Instance<View<T>>,
+}
+
+impl<T: Kind> MapEntities for Viewable<T> {
+    fn map_entities<M: EntityMapper>(&mut self, mapper: &mut M) {
+        self.view.map_entities(mapper);
+    }
+}
+
+impl<T: Kind> Viewable<T> {
+    fn new(view: Instance<View<T>>) -> Self {
+        Self { view }
+    }
+}
+
+#[deprecated(note = "Use `Viewable` instead")]
+pub type Model<T> = Viewable<T>;
+
+impl<T: Kind> Viewable<T> {
+    /// Returns the [`View`] [`Instance`] associated with this [`Viewable`].
+    pub fn view(&self) -> Instance<View<T>> {
+        self.view
+    }
+
+    /// Iterates over the direct children of the [`View`] associated with this [`Viewable`].
+    ///
+    /// The iterator is empty if the view has no children, or if it is despawned.
+    pub fn view_children<'w>(&self, world: &'w World) -> impl Iterator<Item = Entity> + 'w {
+        world
+            .get::<Children>(self.view.entity())
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Returns the first direct child of the [`View`] associated with this [`Viewable`] which has a [`Component`] `C`.
+    pub fn find_view_child<C: Component>(&self, world: &World) -> Option<Entity> {
+        self.view_children(world)
+            .find(|&child| world.get::<C>(child).is_some())
+    }
+}
+
+impl<T: Kind> PartialEq for Viewable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.view == other.view
+    }
+}
+
+impl<T: Kind> Eq for Viewable<T> {}
+
+impl<T: Kind> Hash for Viewable<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.view.hash(state);
+    }
+}
+
+/// [`Component`] of an [`Entity`] associated with a [`Viewable`].
+///
+/// A [`View`] is compared and hashed by its [`Viewable`] entity, so it may be used as a key in collections.
+///
+/// Each view holds the typed [`Instance`] of its viewable, so view systems may access viewable data directly:
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shape;
+///
+/// #[derive(Component)]
+/// struct Size(f32);
+///
+/// fn update_shape_views(
+///     mut views: Query<(&View<Shape>, &mut Transform)>,
+///     shapes: Query<&Size, With<Shape>>,
+/// ) {
+///     for (view, mut transform) in views.iter_mut() {
+///         if let Ok(size) = shapes.get(view.viewable().entity()) {
+///             transform.scale = Vec3::splat(size.0);
+///         }
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(update_shape_views);
+/// ```
+#[derive(Component)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component, MapEntities))]
+pub struct View<T: Kind> {
+    viewable: Instance<T>,
+}
+
+impl<T: Kind> MapEntities for View<T> {
+    fn map_entities<M: EntityMapper>(&mut self, mapper: &mut M) {
+        self.viewable.map_entities(mapper);
+    }
+}
+
+impl<T: Kind> View<T> {
+    #[deprecated(note = "Use `viewable` instead")]
+    pub fn model(&self) -> Instance<T> {
+        self.viewable
+    }
+
+    /// Returns the associated viewable entity.
+    pub fn viewable(&self) -> Instance<T> {
+        self.viewable
+    }
+}
+
+impl<T: Kind> PartialEq for View<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.viewable == other.viewable
+    }
+}
+
+impl<T: Kind> Eq for View<T> {}
+
+impl<T: Kind> Hash for View<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.viewable.hash(state);
+    }
+}
+
+/// Allows [observer](Observer) events to propagate from a [`View`] to its viewable entity.
+///
+/// Relationships are only available from Bevy 0.16 onwards. Until then, [`Traversal`](bevy_ecs::traversal::Traversal)
+/// is the native way to follow a view to its viewable. The traversal never loops, since a viewable entity is
+/// never a view of the same kind.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// #[derive(Component, Clone)]
+/// struct Clicked;
+///
+/// impl Event for Clicked {
+///     type Traversal = &'static View<Bird>;
+///     const AUTO_PROPAGATE: bool = true;
+/// }
+///
+/// fn on_bird_clicked(trigger: Trigger<Clicked>, birds: Query<(), With<Bird>>) {
+///     if birds.contains(trigger.entity()) {
+///         // The view of this bird was clicked ...
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_viewable::<Bird>().add_observer(on_bird_clicked);
+/// ```
+impl<T: Kind> bevy_ecs::traversal::Traversal for &View<T> {
+    fn traverse(view: Self::Item<'_>) -> Option<Entity> {
+        Some(view.viewable.entity())
+    }
+}
+
+/// A [`Component`] which marks a child of a [`View`] entity which should not be despawned with it.
+///
+/// By default, when a view is despawned, all of its children are despawned recursively.
+/// This is undesirable if a child is shared between multiple views, and only parented under one of them.
+/// Children marked with this component are detached from the view instead, and left for the user to manage.
+#[derive(Component, Default)]
+pub struct SharedViewChild;
+
+/// A [`Component`] which marks every [`View`] entity, regardless of its [`Kind`].
+///
+/// This is inserted with the view when it is spawned or [adopted](adopt), and removed when the entity
+/// is no longer a view of any kind. See [`NotView`] for excluding views from queries.
+#[derive(Component, Default)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+pub struct IsView;
+
+/// A [`QueryFilter`](bevy_ecs::query::QueryFilter) which excludes all [`View`] entities.
+///
+/// Views often mirror components of their viewables, such as `Transform`.
+/// Add this filter to gameplay queries which should only ever match the viewables themselves.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// fn move_units(mut units: Query<&mut Transform, NotView>) {
+///     for mut transform in &mut units {
+///         transform.translation.x += 1.0;
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(move_units);
+/// ```
+pub type NotView = Without<IsView>;
+
+/// A [`Component`] which marks every entity with a [`Viewable`], regardless of its [`Kind`].
+///
+/// This is inserted with the [`Viewable`], and removed when the entity no longer has a view of any kind.
+/// Members of a [`SharedView`] are not marked, since they have no [`Viewable`].
+#[derive(Component, Default)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component))]
+pub struct IsViewable;
+
+/// A [`Component`] which marks a [`View`] that is not yet associated with a viewable entity.
+///
+/// Preview views are spawned using [`spawn_preview`]. Until committed with [`commit_preview`], the [`View`]
+/// of a preview refers to [`Instance::PLACEHOLDER`], and it is ignored by all view systems, so it is never
+/// despawned automatically and is not tracked in [`Viewables`].
+#[derive(Component, Default)]
+pub struct PreviewView;
+
+/// A [`Component`] which marks a viewable entity whose [`View`] of [`Kind`] `T` is not spawned.
+///
+/// This component is inserted using [`dormant_view`] and removed using [`wake_view`].
+/// While present, no view of `T` is spawned for the viewable entity, even if it is of kind `T`.
+#[derive(Component)]
+pub struct ViewDormant<T: Kind>(PhantomData<T>);
+
+impl<T: Kind> Default for ViewDormant<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// A [`Component`] which overrides whether the views of a viewable entity are marked with [`Unload`].
+///
+/// By default, a view is marked with [`Unload`] only if its viewable entity is marked with [`Save`].
+/// When a game state is loaded, saved entities are replaced, and so are their views. Views of any other
+/// viewable entities are kept.
+/// Insert this component on the viewable entity before its views are spawned to force (`true`)
+/// or suppress (`false`) this behavior.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ViewUnload(pub bool);
+
+#[derive(Bundle)]
+struct ViewBundle<T: Kind> {
+    view: View<T>,
+    is_view: IsView,
+    unload: Unload,
+}
+
+impl<T: Kind> ViewBundle<T> {
+    pub fn new(viewable: impl Into<Instance<T>>) -> Self {
+        Self {
+            view: View {
+                viewable: viewable.into(),
+            },
+            is_view: IsView,
+            unload: Unload,
+        }
+    }
+}
+
+impl<T: Kind> KindBundle for ViewBundle<T> {
+    type Kind = View<T>;
+}
+
+/// A [`Resource`] which contains a mapping of all viewable entities to their views.
+///
+/// # Usage
+///
+/// Typically, you want to access viewables or views using [`Viewable`] and [`View`] components.
+/// However, in some cases it may be needed to access **all** views for a given viewable.
+/// This [`Resource`] provides an interface for this specific purpose.
+#[derive(Resource, Default)]
+pub struct Viewables {
+    entities: HashMap<Entity, HashSet<Entity>>,
+    kinds: HashMap<TypeId, HashSet<Entity>>,
+    kind_infos: Vec<ViewableKindInfo>,
+    views: HashMap<Entity, Entity>,
+    backlogs: Vec<fn(&mut World) -> usize>,
+}
+
+impl Viewables {
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entities.contains_key(&entity)
+    }
+
+    /// Iterates over all viewed [`Viewable`] entities.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.keys().copied()
+    }
+
+    pub fn is_viewable_kind<T: Kind>(&self) -> bool {
+        self.kinds.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Iterates over all registered viewable kinds, in order of registration.
+    pub fn kinds(&self) -> impl Iterator<Item = &ViewableKindInfo> + '_ {
+        self.kind_infos.iter()
+    }
+
+    #[deprecated(
+        note = "Use `ViewWorld::is_view` instead, which also includes shared views and previews"
+    )]
+    pub fn is_view(&self, entity: Entity) -> bool {
+        self.contains_view(entity)
+    }
+
+    /// Returns `true` if the given [`Entity`] is a view tracked by this registry.
+    pub(crate) fn contains_view(&self, entity: Entity) -> bool {
+        self.views.contains_key(&entity)
+    }
+
+    /// Returns `true` if the given viewable [`Entity`] has a [`View<T>`].
+    ///
+    /// This is equivalent to [`ViewWorld::has_view`], but may be used within systems.
+    pub fn has_view<T: Kind>(&self, entity: Entity) -> bool {
+        self.kinds
+            .get(&TypeId::of::<T>())
+            .is_some_and(|entities| entities.contains(&entity))
+    }
+
+    pub fn is_view_of_kind<T: Kind>(&self, entity: Entity) -> bool {
+        let Some(viewable) = self.views.get(&entity) else {
+            return false;
+        };
+        self.kinds
+            .get(&std::any::TypeId::of::<T>())
+            .is_some_and(|entities| entities.contains(viewable))
+    }
+
+    /// Iterates over all views for a given [`Viewable`] [`Entity`].
+    pub fn views(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.entities
+            .get(&entity)
+            .into_iter()
+            .flat_map(|views| views.iter().copied())
+    }
+
+    fn add_kind<T: Kind>(&mut self) {
+        self.kinds.insert(TypeId::of::<T>(), HashSet::default());
+        self.kind_infos.push(ViewableKindInfo {
+            name: T::debug_name(),
+            type_id: TypeId::of::<T>(),
+        });
+        self.backlogs.push(build_backlog::<T>);
+    }
+
+    fn add<T: Kind>(&mut self, entity: Entity, view: Instance<View<T>>) {
+        self.entities
+            .entry(entity)
+            .or_default()
+            .insert(view.entity());
+        self.kinds
+            .get_mut(&TypeId::of::<T>())
+            .expect("kind must be registered as viewable")
+            .insert(entity);
+        let previous = self.views.insert(view.entity(), entity);
+        debug_assert!(previous.is_none());
+    }
+
+    fn remove<T: Kind>(&mut self, entity: Entity, view: Instance<View<T>>) {
+        // A view may be removed more than once if it is despawned and rebuilt within the same update.
+        let Some(views) = self.entities.get_mut(&entity) else {
+            return;
+        };
+        views.remove(&view.entity());
+        if views.is_empty() {
+            self.entities.remove(&entity);
+        }
+        let kinds = self.kinds.get_mut(&TypeId::of::<T>()).unwrap();
+        kinds.remove(&entity);
+        self.views.remove(&view.entity());
+    }
+}
+
+/// Information about a viewable [`Kind`] registered using [`RegisterView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewableKindInfo {
+    name: String,
+    type_id: TypeId,
+}
+
+impl ViewableKindInfo {
+    /// Returns the debug name of the viewable kind.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`TypeId`] of the viewable kind.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn spawn_view<T: Kind>(
+    objects: Objects<T, (Without<Viewable<T>>, Without<ViewDormant<T>>)>,
+    unload: Query<(Has<Save>, Option<&ViewUnload>)>,
+    debounce: Option<Res<debounce::ViewDebounce<T>>>,
+    frames: Query<&debounce::DebounceFrames<T>>,
+    world: &World,
+    mut commands: Commands,
+) {
+    let vetoes = world.get_resource::<veto::ViewVetoes<T>>();
+    let finders = world.get_resource::<find::ViewFinders<T>>();
+    let budget = world.get_resource::<budget::ViewTimeBudget<T>>();
+    let limit = budget.map_or(usize::MAX, |budget| budget.begin_spawn());
+    let mut spawned = 0;
+    for object in objects.iter() {
+        if spawned >= limit {
+            break;
+        }
+        let entity = object.entity();
+        if let Some(vetoes) = vetoes {
+            if vetoes.is_vetoed(world, object.instance()) {
+                continue;
+            }
+        }
+        if let Some(debounce) = &debounce {
+            if !debounce.is_ready(frames.get(entity).ok()) {
+                continue;
+            }
+            commands
+                .entity(entity)
+                .remove::<debounce::DebounceFrames<T>>();
+        }
+        let (save, unload) = unload.get(entity).unwrap();
+        let unload = unload.map_or(save, |unload| unload.0);
+        match finders.and_then(|finders| finders.find(world, object.instance())) {
+            Some(view) => adopt(object.instance(), view, unload, &mut commands),
+            None if world.contains_resource::<find::ViewAllocator<T>>() => {
+                allocate_view(object.instance(), unload, &mut commands);
+            }
+            None => {
+                insert_view(object.instance(), unload, &mut commands);
+            }
+        }
+        spawned += 1;
+    }
+    if let Some(budget) = budget {
+        budget.end_spawn(spawned as u32);
+    }
+}
+
+/// Spawns the view of [`Kind`] `T` as soon as [`Component`] `C` is added to a viewable instance of `T`.
+///
+/// See [`add_view_reactive`](RegisterView::add_view_reactive) for details.
+#[allow(clippy::type_complexity)]
+fn spawn_view_on_add<T: Kind, C: Component>(
+    trigger: Trigger<OnAdd, C>,
+    objects: Objects<T, (Without<Viewable<T>>, Without<ViewDormant<T>>)>,
+    unload: Query<(Has<Save>, Option<&ViewUnload>)>,
+    paused: Option<Res<ViewsPaused>>,
+    mut commands: Commands,
+) {
+    if !views_active(paused) {
+        return;
+    }
+    let Ok(object) = objects.get(trigger.entity()) else {
+        return;
+    };
+    let (save, unload) = unload.get(trigger.entity()).unwrap();
+    insert_view(
+        object.instance(),
+        unload.map_or(save, |unload| unload.0),
+        &mut commands,
+    );
+}
+
+/// Spawns the view of the given viewable instance using the [`ViewAllocator<T>`](find::ViewAllocator).
+///
+/// See [`add_view_allocator`](RegisterView::add_view_allocator) for details.
+fn allocate_view<T: Kind>(viewable: Instance<T>, unload: bool, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        let view = world.resource_scope(|world, mut allocator: Mut<find::ViewAllocator<T>>| {
+            allocator.allocate(world)
+        });
+        world.flush();
+        if adopt_view(world, viewable, view, unload) {
+            debug!("{view:?} allocated for {viewable:?}");
+        }
+    });
+}
+
+pub(crate) fn insert_view<T: Kind>(
+    viewable: Instance<T>,
+    unload: bool,
+    commands: &mut Commands,
+) -> Instance<View<T>> {
+    let entity = viewable.entity();
+    let view = if unload {
+        commands
+            .spawn_instance(ViewBundle::new(viewable))
+            .instance()
+    } else {
+        commands
+            .spawn_instance(View { viewable })
+            .insert(IsView)
+            .instance()
+    };
+    commands.queue(move |world: &mut World| {
+        world.resource_mut::<Viewables>().add(entity, view);
+        #[cfg(feature = "metrics")]
+        metrics::record_built::<T>(world);
+    });
+    commands.entity(entity).insert(Viewable::new(view));
+    debug!("{view:?} spawned for {entity:?}");
+    view
+}
+
+#[allow(clippy::type_complexity)]
+fn build_view<T: Kind, S: BuildView<T>>(
+    objects: Objects<
+        T,
+        (
+            Or<(
+                Added<Viewable<T>>,
+                (With<Viewable<T>>, Without<Viewable<S>>),
+            )>,
+            S::Filter,
+            Without<Retargeted<T>>,
+        ),
+    >,
+    world: &World,
+    mut commands: Commands,
+) {
+    let pass = info_span!(
+        "build_views",
+        kind = %T::debug_name(),
+        view = %S::debug_name(),
+        count = field::Empty
+    )
+    .entered();
+    let budget = world.get_resource::<budget::ViewTimeBudget<T>>();
+    let mut count = 0;
+    for object in objects.iter() {
+        let _span = info_span!(
+            "build_view",
+            kind = %T::debug_name(),
+            view = %S::debug_name(),
+            entity = ?object.entity()
+        )
+        .entered();
+        count += 1;
+
+        let base_viewable = world.get::<Viewable<T>>(object.entity()).unwrap();
+        let base_view = base_viewable.view();
+
+        // SAFE: `View<S>` will be inserted later.
+        let view = unsafe { base_view.cast_into_unchecked::<View<S>>() };
+
+        // Replace any stale `Viewable<S>` left over from a previous view.
+        commands
+            .entity(object.entity())
+            .insert(Viewable::<S>::new(view));
+
+        commands
+            .entity(base_view.entity())
+            .insert_if_new(View::<S> {
+                // SAFE: `S::Filter` applied to query
+                viewable: unsafe { object.instance().cast_into_unchecked() },
+            });
+
+        if TypeId::of::<T>() != TypeId::of::<S>() {
+            commands
+                .entity(base_view.entity())
+                .insert(ViewVariant::<T>::default());
+        }
+
+        let Some(budget) = budget else {
+            S::build(world, object, commands.instance(base_view));
+            continue;
+        };
+
+        // Measure both the time spent in `S::build` and the time spent applying the commands it queues.
+        let start = budget.now();
+        commands
+            .queue(|world: &mut World| world.resource::<budget::ViewTimeBudget<T>>().begin_build());
+        S::build(world, object, commands.instance(base_view));
+        commands
+            .queue(|world: &mut World| world.resource::<budget::ViewTimeBudget<T>>().end_build());
+        budget.add_spent(budget.now().saturating_sub(start));
+    }
+    pass.record("count", count);
+}
+
+/// Functions used to retarget the links of each view variant of `T`, added for each `add_view::<T, V>`.
+#[derive(Resource)]
+struct ViewVariants<T: Kind> {
+    retargets: Vec<RetargetVariant<T>>,
+}
+
+/// Moves the [`Viewable`] of a view variant from the previous viewable entity to the new one. See [`retarget_variant`].
+type RetargetVariant<T> = fn(&mut World, Entity, Instance<T>, Entity);
+
+impl<T: Kind> Default for ViewVariants<T> {
+    fn default() -> Self {
+        Self {
+            retargets: Vec::new(),
+        }
+    }
+}
+
+fn retarget_variant<T: Kind, S: Kind>(
+    world: &mut World,
+    previous: Entity,
+    viewable: Instance<T>,
+    view: Entity,
+) {
+    let Some(variant) = world.entity_mut(previous).take::<Viewable<S>>() else {
+        return;
+    };
+    world.entity_mut(viewable.entity()).insert(variant);
+    if let Some(mut view) = world.get_mut::<View<S>>(view) {
+        // SAFE: The variant is rebuilt by `retain_view` if the new viewable is not of kind `S`.
+        view.viewable = unsafe { viewable.cast_into_unchecked() };
+    }
+}
+
+/// A [`Resource`] which marks [`Kind`] `T` as one whose views are not spawned by polling during [`ViewSystems::Spawn`].
+///
+/// This is inserted by [`add_view_reactive`](RegisterView::add_view_reactive) and [`add_view_lazy`](RegisterView::add_view_lazy).
+#[derive(Resource)]
+struct ManualViewSpawn<T: Kind>(PhantomData<T>);
+
+impl<T: Kind> Default for ManualViewSpawn<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// A [`Component`] which marks a viewable entity whose [`Viewable<T>`] was inserted by [`retarget_view`].
+///
+/// This prevents the existing view from being built again. It is removed after views are built.
+#[derive(Component)]
+struct Retargeted<T: Kind>(PhantomData<T>);
+
+fn clear_retargeted<T: Kind>(query: Query<Entity, With<Retargeted<T>>>, mut commands: Commands) {
+    for entity in query.iter() {
+        commands.entity(entity).remove::<Retargeted<T>>();
+    }
+}
+
+/// A [`Component`] which marks a [`View<T>`] as built by at least one view variant of `T`.
+#[derive(Component)]
+struct ViewVariant<T: Kind>(PhantomData<T>);
+
+impl<T: Kind> Default for ViewVariant<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn build_view_fallback<T: Kind, F: BuildView<T>>(
+    objects: Objects<T, (Added<Viewable<T>>, F::Filter, Without<Retargeted<T>>)>,
+    variants: Query<(), With<ViewVariant<T>>>,
+    world: &World,
+    mut commands: Commands,
+) {
+    for object in objects.iter() {
+        let view = world.get::<Viewable<T>>(object.entity()).unwrap().view();
+        if variants.contains(view.entity()) {
+            continue;
+        }
+        F::build(world, object, commands.instance(view));
+        debug!("{view:?} built as fallback for {:?}", object.entity());
+    }
+}
+
+fn despawn_view<T: Kind>(
+    views: Query<InstanceRef<View<T>>, (Without<PreviewView>, NotDisappearing)>,
+    query: Query<(), T::Filter>,
+    linked: Option<Res<ViewLinked<T>>>,
+    debounce: Option<Res<debounce::ViewDebounce<T>>>,
+    frames: Query<Option<&debounce::DebounceFrames<T>>>,
+    mut commands: Commands,
+) {
+    let linked = linked.is_none_or(|linked| linked.linked);
+    for view in views.iter() {
+        let viewable = view.viewable();
+        let view = view.instance();
+        if let (Some(debounce), Ok(frames)) = (&debounce, frames.get(viewable.entity())) {
+            if !debounce.is_ready(frames) {
+                continue;
+            }
+        }
+        if query.get(viewable.entity()).is_err() {
+            commands.queue(move |world: &mut World| {
+                if let Ok(mut entity) = world.get_entity_mut(viewable.entity()) {
+                    entity.remove::<(Viewable<T>, debounce::DebounceFrames<T>)>();
+                } else if !linked {
+                    unlink_view(world, viewable, view);
+                    return;
+                }
+                #[cfg(feature = "transition")]
+                if transition::start_view_disappearing(world, viewable, view) {
+                    debug!("{view:?} disappearing for {viewable:?}");
+                    return;
+                }
+                despawn_view_recursive(world, view.entity());
+                debug!("{view:?} despawned for {viewable:?}");
+            });
+        }
+    }
+}
+
+/// A [`QueryFilter`] which excludes views which are [disappearing](ViewDisappearing).
+#[cfg(feature = "transition")]
+type NotDisappearing = Without<ViewDisappearing>;
+
+#[cfg(not(feature = "transition"))]
+type NotDisappearing = ();
+
+/// Returns `true` if the given view is [disappearing](ViewDisappearing), and so no longer linked to its viewable.
+#[cfg(feature = "transition")]
+fn is_disappearing(view: &EntityRef) -> bool {
+    view.contains::<ViewDisappearing>()
+}
+
+#[cfg(not(feature = "transition"))]
+fn is_disappearing(_: &EntityRef) -> bool {
+    false
+}
+
+/// Removes [`View<T>`] from a view whose viewable entity is despawned, and sends [`ViewOrphaned<T>`].
+fn unlink_view<T: Kind>(world: &mut World, viewable: Instance<T>, view: Instance<View<T>>) {
+    world.entity_mut(view.entity()).remove::<View<T>>();
+    if let Some(mut linked) = world.remove_resource::<ViewLinked<T>>() {
+        linked
+            .orphans
+            .retain(|&orphan| world.get_entity(orphan).is_ok());
+        linked.orphans.push(view.entity());
+        world.insert_resource(linked);
+    }
+    if world.contains_resource::<Events<ViewOrphaned<T>>>() {
+        world.send_event(ViewOrphaned::<T> {
+            view: view.entity(),
+            viewable: viewable.entity(),
+            marker: PhantomData,
+        });
+    }
+    debug!("{view:?} unlinked from {viewable:?}");
+}
+
+/// An [`Event`] which is sent when the viewable entity of a view of [`Kind`] `T` is despawned,
+/// but the view is retained because it is not linked.
+///
+/// See [`RegisterView::add_view_linked`] for details.
+#[derive(Event)]
+pub struct ViewOrphaned<T: Kind> {
+    /// The retained view entity, which no longer has a [`View<T>`].
+    pub view: Entity,
+    /// The despawned viewable entity.
+    pub viewable: Entity,
+    marker: PhantomData<T>,
+}
+
+/// An [`Event`] which is triggered by [`despawn_viewable`] before a viewable entity of [`Kind`] `T`
+/// and its view are despawned.
+///
+/// The event targets the viewable entity.
+#[derive(Event)]
+pub struct ViewDestroyed<T: Kind> {
+    /// The view entity, which still exists.
+    pub view: Entity,
+    /// The viewable entity, which still exists.
+    pub viewable: Entity,
+    marker: PhantomData<T>,
+}
+
+/// Set using [`RegisterView::add_view_linked`], and used by [`despawn_view`].
+#[derive(Resource)]
+struct ViewLinked<T: Kind> {
+    linked: bool,
+    /// Views which were orphaned while unlinked, which may have been despawned since.
+    orphans: Vec<Entity>,
+    marker: PhantomData<T>,
+}
+
+/// Callbacks added using [`RegisterView::add_view_on_despawn`], invoked by [`on_remove_view`].
+#[derive(Resource)]
+struct ViewDespawnCallbacks<T: Kind> {
+    callbacks: Vec<ViewDespawnCallback<T>>,
+}
+
+type ViewDespawnCallback<T> = Arc<dyn Fn(Instance<View<T>>, &mut DeferredWorld) + Send + Sync>;
+
+impl<T: Kind> Default for ViewDespawnCallbacks<T> {
+    fn default() -> Self {
+        Self {
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+/// Keeps [`Viewables`] and the associated [`Viewable`] in sync whenever a [`View`] is removed.
+///
+/// Views may be despawned outside of [`despawn_view`], such as when a game state is loaded and all views are unloaded.
+/// If the viewable entity still exists, its [`Viewable`] is removed so that a new view is spawned for it.
+fn on_remove_view<T: Kind>(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let viewable = world.get::<View<T>>(entity).unwrap().viewable();
+    // SAFE: `entity` still has a `View<T>`.
+    let view = unsafe { Instance::<View<T>>::from_entity_unchecked(entity) };
+    if let Some(callbacks) = world.get_resource::<ViewDespawnCallbacks<T>>() {
+        for callback in callbacks.callbacks.clone() {
+            callback(view, &mut world);
+        }
+    }
+    if let Some(mut viewables) = world.get_resource_mut::<Viewables>() {
+        viewables.remove(viewable.entity(), view);
+    }
+    if world.get::<PreviewView>(entity).is_none() {
+        if let Some(mut despawned) = world.get_resource_mut::<DespawnedViews<T>>() {
+            despawned.add(view, viewable);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(mut metrics) = world.get_resource_mut::<ViewMetrics<T>>() {
+            if metrics.add_despawned() {
+                world.send_event_default::<NoViewsRemaining<T>>();
+            }
+        }
+    }
+    world.commands().queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(viewable.entity()) else {
+            return;
+        };
+        if entity
+            .get::<Viewable<T>>()
+            .is_some_and(|viewable| viewable.view() == view)
+        {
+            entity.remove::<Viewable<T>>();
+        }
+        release_viewable(world, viewable.entity());
+    });
+    world.commands().queue(move |world: &mut World| {
+        if world.resource::<Viewables>().contains_view(entity) {
+            return;
+        }
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.remove::<IsView>();
+        }
+    });
+}
+
+/// Marks the entity with [`IsViewable`] whenever a [`Viewable`] is inserted.
+fn on_insert_viewable(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    world.commands().entity(entity).insert(IsViewable);
+}
+
+/// Queues [`release_viewable`] whenever a [`Viewable`] is removed.
+fn on_remove_viewable(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    world
+        .commands()
+        .queue(move |world: &mut World| release_viewable(world, entity));
+}
+
+/// Removes [`IsViewable`] from the given entity if it no longer has a view of any kind.
+fn release_viewable(world: &mut World, entity: Entity) {
+    if world.resource::<Viewables>().contains(entity) {
+        return;
+    }
+    if let Ok(mut entity) = world.get_entity_mut(entity) {
+        entity.remove::<IsViewable>();
+    }
+}
+
+/// Despawns a view entity and its children, except for any children which are views themselves or [`SharedViewChild`].
+///
+/// Nested views belong to their own viewables, so they are detached instead of despawned.
+fn despawn_view_recursive(world: &mut World, view: Entity) {
+    let detached: Vec<Entity> = world
+        .get::<Children>(view)
+        .into_iter()
+        .flatten()
+        .copied()
+        .filter(|&child| world.is_view(child) || world.get::<SharedViewChild>(child).is_some())
+        .collect();
+    for child in detached {
+        world.entity_mut(child).remove_parent();
+    }
+    if let Ok(view_entity) = world.get_entity_mut(view) {
+        view_entity.despawn_recursive();
+    }
+}
+
+/// Rebuilds the [`View`] of any [`Viewable`] which is still of [`Kind`] `T`, but no longer of kind `S`.
+///
+/// Bevy cannot negate an arbitrary query filter, so instead every model with a `Viewable<S>` is tested
+/// against the positive filter. Anything built by `S` cannot be undone, so the entire view is rebuilt.
+///
+/// Models which are no longer of kind `T` are handled by [`despawn_view`].
+#[allow(clippy::type_complexity)]
+fn retain_view<T: Kind, S: BuildView<T>>(
+    viewables: Query<(Entity, Option<&Viewable<T>>), With<Viewable<S>>>,
+    base: Query<(), T::Filter>,
+    query: Query<(), (T::Filter, S::Filter)>,
+    mut commands: Commands,
+) {
+    for (entity, base_viewable) in viewables.iter() {
+        if query.contains(entity) {
+            continue;
+        }
+        commands.entity(entity).remove::<Viewable<S>>();
+        if let Some(base_viewable) = base_viewable {
+            if base.contains(entity) {
+                rebuild_view(entity, base_viewable.view(), &mut commands);
+            }
+        }
+    }
+}
+
+/// Despawns the current [`View`] associated with this [`Viewable`] and rebuilds a new one.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// enum Shape {
+///     Square,
+///     Circle,
+/// }
+///
+/// impl BuildView for Shape {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         let shape = world.get::<Shape>(object.entity());
+///         // ...
+///     }
+/// }
+///
+/// fn rebuild_shape_views(query: Query<InstanceRef<Viewable<Shape>>>, mut commands: Commands) {
+///     for viewable in query.iter() {
+///         moonshine_view::rebuild(viewable, &mut commands);
+///     }
+/// }
+/// ```
+pub fn rebuild<T: Kind>(viewable: InstanceRef<Viewable<T>>, commands: &mut Commands) {
+    rebuild_view(viewable.entity(), viewable.view(), commands);
+}
+
+fn rebuild_view<T: Kind>(entity: Entity, view: Instance<View<T>>, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        preserve::preserve_view::<T>(world, entity, view.entity());
+        despawn_view_recursive(world, view.entity());
+    });
+    commands.entity(entity).remove::<Viewable<T>>();
+}
+
+/// Spawns views for all viewable instances of [`Kind`] `T` without a view which also match the filter `F`.
+///
+/// The filter is composed with the kind filter, so only entities which match `(T::Filter, F)` are considered,
+/// excluding any which already have a [`Viewable<T>`] or are [`ViewDormant<T>`]. This runs once when the
+/// commands are applied, and the new views are built during the next [`ViewSystems::Build`].
+///
+/// This is intended for kinds registered using [`add_view_lazy`](RegisterView::add_view_lazy), such as to
+/// build views for all entities within a room as it is streamed in. Views of other kinds are spawned
+/// automatically anyway.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// #[derive(Component)]
+/// struct InCurrentRoom;
+///
+/// impl BuildView for Enemy {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn enter_room(mut commands: Commands) {
+///     moonshine_view::build_views_matching::<Enemy, With<InCurrentRoom>>(&mut commands);
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(enter_room);
+/// ```
+pub fn build_views_matching<T: Kind, F: QueryFilter + 'static>(commands: &mut Commands) {
+    commands.queue(|world: &mut World| {
+        let instances: Vec<_> = world
+            .query_filtered::<(Instance<T>, Has<Save>, Option<&ViewUnload>), (
+                T::Filter,
+                F,
+                Without<Viewable<T>>,
+                Without<ViewDormant<T>>,
+            )>()
+            .iter(world)
+            .map(|(instance, save, unload)| (instance, unload.map_or(save, |unload| unload.0)))
+            .collect();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        for (instance, unload) in instances {
+            insert_view(instance, unload, &mut commands);
+        }
+        queue.apply(world);
+    });
+}
+
+/// Despawns the [`View`] of the given viewable instance and marks it as [`ViewDormant`].
+///
+/// A dormant viewable keeps all of its state, but no view is spawned for it until [`wake_view`] is called.
+/// This is useful to save resources on views which are not visible, such as those of distant models.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Tree;
+///
+/// impl BuildView for Tree {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn cull_trees(
+///     camera: Single<&Transform, With<Camera>>,
+///     trees: Query<(Instance<Tree>, &Transform, Has<ViewDormant<Tree>>)>,
+///     mut commands: Commands,
+/// ) {
+///     for (tree, transform, dormant) in trees.iter() {
+///         let far = transform.translation.distance(camera.translation) > 1000.0;
+///         if far && !dormant {
+///             moonshine_view::dormant_view(tree, &mut commands);
+///         } else if !far && dormant {
+///             moonshine_view::wake_view(tree, &mut commands);
+///         }
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(cull_trees);
+/// ```
+pub fn dormant_view<T: Kind>(viewable: Instance<T>, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(viewable.entity()) else {
+            return;
+        };
+        entity.insert(ViewDormant::<T>::default());
+        if let Some(view) = entity.take::<Viewable<T>>().map(|viewable| viewable.view()) {
+            despawn_view_recursive(world, view.entity());
+            debug!("{view:?} despawned for dormant {viewable:?}");
+        }
+    });
+}
+
+/// Removes [`ViewDormant`] from the given viewable instance, so that its [`View`] is spawned again.
+///
+/// The new view is spawned and built from scratch, as if the viewable was just spawned.
+pub fn wake_view<T: Kind>(viewable: Instance<T>, commands: &mut Commands) {
+    if let Some(mut entity) = commands.get_entity(viewable.entity()) {
+        entity.remove::<ViewDormant<T>>();
+    }
+}
+
+/// Triggers [`ViewDestroyed<T>`] for the given viewable instance, and then despawns its [`View`] and the viewable.
+///
+/// The outcome is equivalent to despawning the viewable entity, except that the view is despawned immediately,
+/// without any [transitions](RegisterView::add_view_transitions), even if it is [not linked](RegisterView::add_view_linked).
+/// Observers of [`ViewDestroyed<T>`] are run before either entity is despawned, so they may still access both.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// impl BuildView for Enemy {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn on_enemy_destroyed(trigger: Trigger<ViewDestroyed<Enemy>>, transforms: Query<&Transform>) {
+///     if let Ok(transform) = transforms.get(trigger.event().view) {
+///         // Spawn an explosion at the view ...
+///     }
+/// }
+///
+/// fn kill_enemies(enemies: Query<Instance<Enemy>>, mut commands: Commands) {
+///     for enemy in enemies.iter() {
+///         moonshine_view::despawn_viewable(enemy, &mut commands);
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(kill_enemies);
+/// let mut app = App::new();
+/// app.add_viewable::<Enemy>().add_observer(on_enemy_destroyed);
+/// ```
+pub fn despawn_viewable<T: Kind>(viewable: Instance<T>, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        let entity = viewable.entity();
+        let Some(view) = world.get::<Viewable<T>>(entity).map(Viewable::view) else {
+            world.despawn(entity);
+            return;
+        };
+        world.trigger_targets(
+            ViewDestroyed::<T> {
+                view: view.entity(),
+                viewable: entity,
+                marker: PhantomData,
+            },
+            entity,
+        );
+        despawn_view_recursive(world, view.entity());
+        world.despawn(entity);
+        debug!("{view:?} despawned with {entity:?}");
+    });
+}
+
+/// Adopts an existing [`Entity`] as the [`View`] of the given viewable instance, instead of spawning a new one.
+///
+/// The adopted entity keeps all of its existing components and children. Once adopted, the view is built
+/// as usual by each registered [`BuildView`] implementation, and despawned with its viewable.
+///
+/// If `unload` is `true`, the adopted entity is also marked with [`Unload`], like any other view.
+/// Otherwise, it is up to the caller to decide whether the view should be despawned when a game state is loaded.
+///
+/// The viewable should be adopted before its view is spawned, or it is ignored.
+///
+/// This may also be used when view entities must be reproducible, such as in a deterministic networked game.
+/// Views spawned by this crate are reserved while systems run in parallel, so their entity IDs depend on
+/// scheduling. Instead, spawn the view entities in a deterministic order and adopt them.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Door;
+///
+/// impl BuildView for Door {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn spawn_door(mut commands: Commands) {
+///     let door = commands.spawn_instance(Door).instance();
+///     let view = commands.spawn(Name::new("Pre-placed Door")).id();
+///     moonshine_view::adopt(door, view, false, &mut commands);
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(spawn_door);
+/// ```
+pub fn adopt<T: Kind>(viewable: Instance<T>, view: Entity, unload: bool, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        if adopt_view(world, viewable, view, unload) {
+            debug!("{view:?} adopted by {viewable:?}");
+        }
+    });
+}
+
+fn adopt_view<T: Kind>(
+    world: &mut World,
+    viewable: Instance<T>,
+    view: Entity,
+    unload: bool,
+) -> bool {
+    let entity = viewable.entity();
+    if world.get::<Viewable<T>>(entity).is_some() {
+        warn!("{entity:?} already has a view; {view:?} is not adopted");
+        return false;
+    }
+    let Ok(mut view_entity) = world.get_entity_mut(view) else {
+        warn!("{view:?} does not exist and cannot be adopted by {entity:?}");
+        return false;
+    };
+    view_entity.insert((View { viewable }, IsView));
+    if unload {
+        view_entity.insert(Unload);
+    }
+    // SAFE: `View<T>` was just inserted.
+    let view = unsafe { Instance::<View<T>>::from_entity_unchecked(view) };
+    world.entity_mut(entity).insert(Viewable::new(view));
+    world
+        .get_resource_or_insert_with(Viewables::default)
+        .add(entity, view);
+    #[cfg(feature = "metrics")]
+    metrics::record_built::<T>(world);
+    true
+}
+
+/// Spawns a [`PreviewView`] of [`Kind`] `T`, which is not associated with any viewable entity.
+///
+/// This is useful to display a model which does not exist yet, such as a building placement preview.
+/// Since there is no viewable to build the view from, it is up to the caller to build the preview using
+/// the returned [`ViewCommands`].
+///
+/// A preview is not despawned automatically. To discard it, despawn it like any other entity.
+/// To keep it, use [`commit_preview`] once the viewable is spawned.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Building;
+///
+/// #[derive(Resource)]
+/// struct Placement(Instance<View<Building>>);
+///
+/// fn start_placement(mut commands: Commands) {
+///     let preview = moonshine_view::spawn_preview::<Building>(&mut commands).instance();
+///     commands.insert_resource(Placement(preview));
+/// }
+///
+/// fn confirm_placement(placement: Res<Placement>, mut commands: Commands) {
+///     let building = commands.spawn_instance(Building).instance();
+///     moonshine_view::commit_preview(placement.0, building, &mut commands);
+///     commands.remove_resource::<Placement>();
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(start_placement);
+/// # bevy_ecs::system::assert_is_system(confirm_placement);
+/// ```
+pub fn spawn_preview<'a, T: Kind>(commands: &'a mut Commands) -> ViewCommands<'a, T> {
+    let mut view = commands.spawn_instance(ViewBundle::<T>::new(Instance::PLACEHOLDER));
+    view.insert(PreviewView);
+    view
+}
+
+/// Commits a [`PreviewView`] spawned with [`spawn_preview`] as the [`View`] of the given viewable instance.
+///
+/// The preview is [adopted](adopt) by the viewable, so it keeps all of its existing components and children,
+/// and it is then built as usual by each registered [`BuildView`] implementation. Any preview-specific
+/// components (such as a ghost material) should be removed by the caller.
+///
+/// Like [`adopt`], the preview must be committed before a view is spawned for the viewable, ideally in
+/// the same system which spawns it.
+pub fn commit_preview<T: Kind>(
+    preview: Instance<View<T>>,
+    viewable: Instance<T>,
+    commands: &mut Commands,
+) {
+    if let Some(mut entity) = commands.get_entity(preview.entity()) {
+        entity.remove::<PreviewView>();
+    }
+    adopt(viewable, preview.entity(), true, commands);
+}
+
+/// Changes the viewable instance associated with the given [`View`], without despawning or rebuilding it.
+///
+/// The view keeps all of its existing components and children. The [`Viewable`] of the previous viewable entity
+/// is moved to the new one. If the previous viewable entity is still of [`Kind`] `T`, a new view is spawned for it.
+///
+/// The new viewable entity must not already have a view of kind `T`, or the view is not retargeted.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Spirit;
+///
+/// #[derive(Component)]
+/// struct Body;
+///
+/// impl BuildView for Spirit {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn possess(
+///     spirit: Single<(Entity, &Viewable<Spirit>)>,
+///     body: Single<Entity, With<Body>>,
+///     mut commands: Commands,
+/// ) {
+///     let (spirit, viewable) = *spirit;
+///     commands.entity(spirit).remove::<Spirit>();
+///     commands.entity(*body).insert(Spirit);
+///     // SAFE: `Spirit` is inserted into the body before the view is retargeted.
+///     let body = unsafe { Instance::<Spirit>::from_entity_unchecked(*body) };
+///     moonshine_view::retarget_view(viewable.view(), body, &mut commands);
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(possess);
+/// ```
+pub fn retarget_view<T: Kind>(
+    view: Instance<View<T>>,
+    viewable: Instance<T>,
+    commands: &mut Commands,
+) {
+    commands.queue(move |world: &mut World| {
+        let entity = viewable.entity();
+        let Ok(viewable_entity) = world.get_entity(entity) else {
+            warn!("{entity:?} does not exist; {view:?} is not retargeted");
+            return;
+        };
+        if viewable_entity.contains::<Viewable<T>>() {
+            warn!("{entity:?} already has a view; {view:?} is not retargeted");
+            return;
+        }
+        let Some(mut view_data) = world.get_mut::<View<T>>(view.entity()) else {
+            warn!("{view:?} does not exist and cannot be retargeted to {entity:?}");
+            return;
+        };
+        let previous = std::mem::replace(&mut view_data.viewable, viewable);
+        world
+            .entity_mut(entity)
+            .insert((Viewable::new(view), Retargeted::<T>(PhantomData)));
+        let mut viewables = world.resource_mut::<Viewables>();
+        viewables.remove(previous.entity(), view);
+        viewables.add(entity, view);
+        if let Ok(mut previous_entity) = world.get_entity_mut(previous.entity()) {
+            if previous_entity
+                .get::<Viewable<T>>()
+                .is_some_and(|viewable| viewable.view() == view)
+            {
+                previous_entity.remove::<Viewable<T>>();
+            }
+            let retargets = world
+                .get_resource::<ViewVariants<T>>()
+                .map(|variants| variants.retargets.clone())
+                .unwrap_or_default();
+            for retarget in retargets {
+                retarget(world, previous.entity(), viewable, view.entity());
+            }
+        }
+        debug!("{view:?} retargeted from {previous:?} to {entity:?}");
+    });
 }
 
-/// Extension trait used to register views using an [`App`].
-pub trait RegisterView {
-    /// Adds a view for a given [`Kind`].
-    fn add_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self;
+/// Adds an observer to the given [`View`] which is triggered when the view is despawned.
+///
+/// The observer is triggered before any of the view's components are removed, so it may still access them.
+/// This is useful to release any resources which were allocated specifically for one view.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// #[derive(Component)]
+/// struct Buffer(usize);
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+///         view.insert(Buffer(42));
+///     }
+/// }
+///
+/// fn observe_bird_views(query: Query<&Viewable<Bird>, Added<Viewable<Bird>>>, mut commands: Commands) {
+///     for viewable in query.iter() {
+///         moonshine_view::on_despawn(
+///             viewable.view(),
+///             |trigger: Trigger<OnRemove, View<Bird>>, query: Query<&Buffer>| {
+///                 let buffer = query.get(trigger.entity()).unwrap();
+///                 // Release buffer ...
+///             },
+///             &mut commands,
+///         );
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(observe_bird_views);
+/// ```
+pub fn on_despawn<T: Kind, M>(
+    view: Instance<View<T>>,
+    observer: impl IntoObserverSystem<OnRemove, View<T>, M>,
+    commands: &mut Commands,
+) {
+    commands.entity(view.entity()).observe(observer);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Bird;
+
+    #[derive(Component)]
+    struct Monkey;
+
+    struct Creature;
+
+    impl Kind for Creature {
+        type Filter = Or<(With<Bird>, With<Monkey>)>;
+    }
+
+    impl BuildView for Creature {
+        fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+    }
+
+    impl BuildView<Creature> for Bird {
+        fn build(_: &World, _: Object<Creature>, _: ViewCommands<Creature>) {}
+    }
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Creature>();
+        app.add_plugins(MinimalPlugins).add_view::<Creature, Bird>();
+        app
+    }
+
+    fn queue(app: &mut ViewTestApp, f: impl FnOnce(&mut Commands)) {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, app.world());
+        f(&mut commands);
+        queue.apply(app.world_mut());
+    }
+
+    #[test]
+    fn view_is_rebuilt_when_variant_filter_stops_matching() {
+        let mut app = app();
+        let creature = app.world_mut().spawn((Bird, Monkey)).id();
+        app.update();
+        let view = app.world().view::<Creature>(creature).unwrap();
+        assert_eq!(
+            app.world().view::<Bird>(creature).map(|view| view.entity()),
+            Some(view.entity())
+        );
+
+        app.world_mut().entity_mut(creature).remove::<Bird>();
+        app.update();
+        assert!(app.world().get::<Viewable<Bird>>(creature).is_none());
+        assert!(app.world().get_entity(view.entity()).is_err());
+        app.update();
+        let rebuilt = app.world().view::<Creature>(creature).unwrap();
+        assert_ne!(rebuilt, view);
+
+        app.world_mut().entity_mut(creature).insert(Bird);
+        app.update();
+        assert_eq!(
+            app.world().view::<Bird>(creature).map(|view| view.entity()),
+            Some(rebuilt.entity())
+        );
+        assert_view_invariants::<Creature>(app.world());
+        assert_view_invariants::<Bird>(app.world());
+
+        app.world_mut().despawn(creature);
+        app.update();
+        assert!(app.world().get_entity(rebuilt.entity()).is_err());
+        assert_eq!(app.world().view_count::<Creature>(), 0);
+    }
+
+    #[test]
+    fn views_and_viewables_as_map_keys() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn(Monkey).id();
+        app.update();
+
+        let world = app.world();
+        let viewable = |entity| world.get::<Viewable<Creature>>(entity).unwrap();
+        let view = |entity| {
+            let view = viewable(entity).view();
+            world.get::<View<Creature>>(view.entity()).unwrap()
+        };
+
+        let by_viewable: HashMap<&Viewable<Creature>, Entity> =
+            [(viewable(a), a), (viewable(b), b)].into_iter().collect();
+        assert_eq!(by_viewable.get(viewable(a)), Some(&a));
+        assert_eq!(by_viewable.get(viewable(b)), Some(&b));
+        assert!(viewable(a) != viewable(b));
+
+        let by_view: HashMap<&View<Creature>, Entity> =
+            [(view(a), a), (view(b), b)].into_iter().collect();
+        assert_eq!(by_view.get(view(a)), Some(&a));
+        assert_eq!(by_view.get(view(b)), Some(&b));
+
+        let by_instance: HashMap<Instance<View<Creature>>, Entity> = [a, b]
+            .into_iter()
+            .map(|entity| (viewable(entity).view(), entity))
+            .collect();
+        assert_eq!(by_instance.get(&viewable(a).view()), Some(&a));
+        assert_eq!(by_instance.len(), 2);
+    }
+
+    #[test]
+    fn viewable_and_view_counts() {
+        let mut app = app();
+        for _ in 0..3 {
+            app.world_mut().spawn(Bird);
+        }
+        for _ in 0..2 {
+            app.world_mut().spawn(Monkey);
+        }
+        assert_eq!(app.world().viewable_count::<Creature>(), 0);
+        assert_eq!(app.world().view_count::<Creature>(), 0);
+
+        app.update();
+        assert_eq!(app.world().viewable_count::<Creature>(), 5);
+        assert_eq!(app.world().view_count::<Creature>(), 5);
+        assert_eq!(app.world().viewable_count::<Bird>(), 3);
+        assert_eq!(app.world().view_count::<Bird>(), 3);
+    }
+
+    #[test]
+    fn registered_kinds_in_order() {
+        #[derive(Component)]
+        struct Fish;
+
+        impl BuildView for Fish {
+            fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+        }
+
+        #[derive(Component)]
+        struct Worm;
+
+        impl BuildView for Worm {
+            fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+        }
+
+        let mut app = app();
+        app.add_viewable::<Fish>()
+            .add_viewable::<Creature>()
+            .add_viewable::<Worm>();
+
+        let names: Vec<String> = app
+            .world()
+            .resource::<Viewables>()
+            .kinds()
+            .map(|info| info.name().to_owned())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                Creature::debug_name(),
+                Fish::debug_name(),
+                Worm::debug_name()
+            ]
+        );
+        let types: Vec<TypeId> = app
+            .world()
+            .resource::<Viewables>()
+            .kinds()
+            .map(ViewableKindInfo::type_id)
+            .collect();
+        assert_eq!(
+            types,
+            [
+                TypeId::of::<Creature>(),
+                TypeId::of::<Fish>(),
+                TypeId::of::<Worm>()
+            ]
+        );
+    }
+
+    #[test]
+    fn on_despawn_runs_once_before_view_is_gone() {
+        #[derive(Component)]
+        struct Buffer(u32);
+
+        #[derive(Resource, Default)]
+        struct Released(Vec<u32>);
+
+        let mut app = app();
+        app.init_resource::<Released>();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap();
+        app.world_mut().entity_mut(view.entity()).insert(Buffer(7));
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, app.world());
+        on_despawn(
+            view,
+            |trigger: Trigger<OnRemove, View<Creature>>,
+             buffers: Query<&Buffer>,
+             mut released: ResMut<Released>| {
+                released.0.push(buffers.get(trigger.entity()).unwrap().0);
+            },
+            &mut commands,
+        );
+        queue.apply(app.world_mut());
+
+        app.world_mut().despawn(bird);
+        app.update();
+        app.update();
+        assert!(app.world().get_entity(view.entity()).is_err());
+        assert_eq!(app.world().resource::<Released>().0, [7]);
+    }
+
+    #[test]
+    fn views_unload_only_with_saved_viewables() {
+        let mut app = app();
+        let saved = app.world_mut().spawn((Bird, Save)).id();
+        let plain = app.world_mut().spawn(Bird).id();
+        let forced = app.world_mut().spawn((Bird, ViewUnload(true))).id();
+        let kept = app.world_mut().spawn((Bird, Save, ViewUnload(false))).id();
+        app.update();
+
+        let unloads = |app: &ViewTestApp, viewable: Entity| {
+            let view = app.world().view::<Creature>(viewable).unwrap();
+            app.world().get::<Unload>(view.entity()).is_some()
+        };
+        assert!(unloads(&app, saved));
+        assert!(!unloads(&app, plain));
+        assert!(unloads(&app, forced));
+        assert!(!unloads(&app, kept));
+    }
+
+    #[test]
+    fn classify_views_and_viewables() {
+        let mut app = app();
+        let model = app.world_mut().spawn(Bird).id();
+        let other = app.world_mut().spawn_empty().id();
+        app.update();
+
+        let view = app.world().view::<Creature>(model).unwrap().entity();
+        assert!(app.world().is_viewable(model));
+        assert!(!app.world().is_view(model));
+        assert!(app.world().is_view(view));
+        assert!(!app.world().is_viewable(view));
+        assert!(!app.world().is_view(other));
+        assert!(!app.world().is_viewable(other));
+
+        app.world_mut().entity_mut(model).remove::<Bird>();
+        app.update();
+        assert!(!app.world().is_viewable(model));
+        assert!(!app.world().is_view(view));
+    }
+
+    #[test]
+    fn retarget_view_moves_view_to_new_viewable() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn_empty().id();
+        app.update();
+        let view = app.world().view::<Creature>(a).unwrap();
+
+        app.world_mut().entity_mut(a).remove::<Bird>();
+        app.world_mut().entity_mut(b).insert(Bird);
+        // SAFE: `Bird` was just inserted.
+        let target = unsafe { Instance::<Creature>::from_entity_unchecked(b) };
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, app.world());
+        retarget_view(view, target, &mut commands);
+        queue.apply(app.world_mut());
+        app.update();
+
+        assert!(app.world().view::<Creature>(b) == Some(view));
+        assert!(app.world().view::<Creature>(a).is_none());
+        assert_eq!(
+            app.world()
+                .get::<View<Creature>>(view.entity())
+                .unwrap()
+                .viewable(),
+            target
+        );
+        assert!(app.world().is_viewable(b));
+        assert!(!app.world().is_viewable(a));
+        assert_eq!(app.world().view_count::<Creature>(), 1);
+        assert_view_invariants::<Creature>(app.world());
+    }
+
+    #[test]
+    fn suppress_view_despawn_keeps_views_within_closure() {
+        let mut app = app();
+        let kept = app.world_mut().spawn(Bird).id();
+        let removed = app.world_mut().spawn(Bird).id();
+        app.update();
+        let kept_view = app.world().view::<Creature>(kept).unwrap().entity();
+        let removed_view = app.world().view::<Creature>(removed).unwrap().entity();
+
+        app.world_mut()
+            .suppress_view_despawn::<Creature, _>(|world| {
+                world.despawn(kept);
+            });
+        app.update();
+        assert!(app.world().get_entity(kept_view).is_ok());
+        assert!(app.world().get::<View<Creature>>(kept_view).is_none());
+
+        app.world_mut().despawn(removed);
+        app.update();
+        assert!(app.world().get_entity(removed_view).is_err());
+    }
+
+    #[test]
+    fn adopted_view_keeps_its_components() {
+        #[derive(Component)]
+        struct Placed(u32);
+
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        let view = app.world_mut().spawn(Placed(7)).id();
+        // SAFE: `bird` was just spawned as a `Bird`.
+        let bird_instance = unsafe { Instance::<Creature>::from_entity_unchecked(bird) };
+        queue(&mut app, |commands| {
+            adopt(bird_instance, view, false, commands)
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().view::<Creature>(bird).map(|view| view.entity()),
+            Some(view)
+        );
+        assert_eq!(app.world().get::<Placed>(view).unwrap().0, 7);
+        assert!(app.world().get::<Unload>(view).is_none());
+        assert_eq!(app.world().view_count::<Creature>(), 1);
+        assert_view_invariants::<Creature>(app.world());
+    }
+
+    #[test]
+    fn dormant_view_is_rebuilt_on_wake() {
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap();
+        // SAFE: `bird` was just spawned as a `Bird`.
+        let bird_instance = unsafe { Instance::<Creature>::from_entity_unchecked(bird) };
+
+        queue(&mut app, |commands| dormant_view(bird_instance, commands));
+        app.update();
+        assert!(app.world().get_entity(view.entity()).is_err());
+        assert!(app.world().view::<Creature>(bird).is_none());
+        assert!(app.world().get::<ViewDormant<Creature>>(bird).is_some());
+
+        queue(&mut app, |commands| wake_view(bird_instance, commands));
+        app.update();
+        let woken = app.world().view::<Creature>(bird).unwrap();
+        assert_ne!(woken, view);
+        assert_view_invariants::<Creature>(app.world());
+    }
+
+    #[test]
+    fn paused_views_are_not_built() {
+        let mut app = app();
+        app.pause_views();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        assert!(app.world().view::<Creature>(bird).is_none());
+
+        app.resume_views();
+        app.update();
+        assert!(app.world().view::<Creature>(bird).is_some());
+    }
+
+    #[test]
+    fn build_system_runs_per_view() {
+        #[derive(Resource, Default)]
+        struct Built(Vec<Entity>);
+
+        let mut app = app();
+        app.init_resource::<Built>()
+            .add_view_build_system::<Creature, _>(
+                |In(view): In<Instance<View<Creature>>>, mut built: ResMut<Built>| {
+                    built.0.push(view.entity());
+                },
+            );
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn(Monkey).id();
+        app.update();
+        app.update();
+
+        let mut built = app.world().resource::<Built>().0.clone();
+        built.sort();
+        let mut views = [a, b].map(|entity| app.world().view::<Creature>(entity).unwrap().entity());
+        views.sort();
+        assert_eq!(built, views);
+    }
+
+    #[test]
+    fn view_outlives_viewable_until_last() {
+        #[derive(Resource)]
+        struct Target(Entity, Entity);
+
+        #[derive(Resource, Default)]
+        struct Alive(bool);
+
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+        app.insert_resource(Target(bird, view))
+            .init_resource::<Alive>()
+            .add_systems(Update, |target: Res<Target>, mut commands: Commands| {
+                commands.entity(target.0).despawn();
+            })
+            .add_systems(
+                PostUpdate,
+                |target: Res<Target>,
+                 views: Query<(), With<View<Creature>>>,
+                 mut alive: ResMut<Alive>| {
+                    alive.0 = views.contains(target.1);
+                },
+            );
+        app.update();
+
+        assert!(app.world().resource::<Alive>().0);
+        assert!(app.world().get_entity(view).is_err());
+    }
+
+    #[test]
+    fn find_tagged_view_child() {
+        #[derive(Component)]
+        struct Head;
+
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+        let head = app.world_mut().spawn(Head).id();
+        let wing = app.world_mut().spawn_empty().id();
+        app.world_mut().entity_mut(view).add_children(&[wing, head]);
+
+        let world = app.world();
+        let viewable = world.get::<Viewable<Creature>>(bird).unwrap();
+        assert_eq!(
+            viewable.view_children(world).collect::<Vec<_>>(),
+            [wing, head]
+        );
+        assert_eq!(viewable.find_view_child::<Head>(world), Some(head));
+        assert_eq!(viewable.find_view_child::<Bird>(world), None);
+    }
+
+    #[test]
+    fn orphaned_view_survives_with_event() {
+        let mut app = app();
+        app.add_view_linked::<Creature>(false);
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+
+        app.world_mut().despawn(bird);
+        app.update();
+        assert!(app.world().get_entity(view).is_ok());
+        assert!(app.world().get::<View<Creature>>(view).is_none());
+        let events = app.world().resource::<Events<ViewOrphaned<Creature>>>();
+        let orphans: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| (event.view, event.viewable))
+            .collect();
+        assert_eq!(orphans, [(view, bird)]);
+    }
+
+    #[test]
+    fn reactive_view_spawned_without_polling() {
+        let mut app = app();
+        app.add_view_reactive::<Creature, Bird>();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.world_mut().flush();
+        assert!(app.world().has_view::<Creature>(bird));
+
+        let monkey = app.world_mut().spawn(Monkey).id();
+        app.update();
+        assert!(!app.world().has_view::<Creature>(monkey));
+        assert_view_invariants::<Creature>(app.world());
+    }
+
+    #[test]
+    fn despawn_all_views_keeps_viewables() {
+        #[derive(Component)]
+        struct Fish;
+
+        impl BuildView for Fish {
+            fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+        }
+
+        let mut app = app();
+        app.add_viewable::<Fish>();
+        let bird = app.world_mut().spawn(Bird).id();
+        let fish = app.world_mut().spawn(Fish).id();
+        app.update();
+        let views = [
+            app.world().view::<Creature>(bird).unwrap().entity(),
+            app.world().view::<Fish>(fish).unwrap().entity(),
+        ];
+
+        app.world_mut().despawn_all_views();
+        for view in views {
+            assert!(app.world().get_entity(view).is_err());
+        }
+        assert_eq!(app.world().view_count::<Creature>(), 0);
+        assert_eq!(app.world().view_count::<Fish>(), 0);
+        assert!(app.world().get::<Bird>(bird).is_some());
+        assert!(app.world().get::<Fish>(fish).is_some());
+    }
+
+    #[test]
+    fn higher_priority_views_built_first() {
+        #[derive(Component)]
+        struct Terrain;
 
-    /// Adds a given [`Kind`] as viewable.
-    fn add_viewable<T: BuildView>(&mut self) -> &mut Self {
-        self.add_view::<T, T>()
+        #[derive(Component)]
+        struct Ground;
+
+        #[derive(Component)]
+        struct Unit(Entity);
+
+        #[derive(Resource, Default)]
+        struct Grounded(Vec<bool>);
+
+        impl BuildView for Terrain {
+            fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+                view.insert(Ground);
+            }
+        }
+
+        impl BuildView for Unit {
+            fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+                let terrain = world.get::<Unit>(object.entity()).unwrap().0;
+                let grounded = world
+                    .get::<Viewable<Terrain>>(terrain)
+                    .is_some_and(|viewable| {
+                        world.get::<Ground>(viewable.view().entity()).is_some()
+                    });
+                view.commands().queue(move |world: &mut World| {
+                    world.resource_mut::<Grounded>().0.push(grounded)
+                });
+            }
+        }
+
+        let mut app = ViewTestApp::with_viewable::<Unit>();
+        app.add_plugins(MinimalPlugins)
+            .add_viewable::<Terrain>()
+            .init_resource::<Grounded>()
+            .add_view_priority::<Unit>(1)
+            .add_view_priority::<Terrain>(0);
+        let terrain = app.world_mut().spawn(Terrain).id();
+        app.world_mut().spawn(Unit(terrain));
+        app.update();
+
+        assert_eq!(app.world().resource::<Grounded>().0, [true]);
     }
 
-    #[deprecated(note = "Use `add_view` instead")]
-    fn register_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self {
-        self.add_view::<T, V>()
+    #[test]
+    fn component_is_viewable_without_kind() {
+        #[derive(Component)]
+        struct Tree;
+
+        let mut app = app();
+        app.add_view_component::<Tree>();
+        let tree = app.world_mut().spawn_empty().id();
+        app.update();
+        assert!(!app.world().has_view::<Tree>(tree));
+
+        app.world_mut().entity_mut(tree).insert(Tree);
+        app.update();
+        let view = app.world().view::<Tree>(tree).unwrap();
+        assert!(app.world().get::<View<Tree>>(view.entity()).is_some());
     }
 
-    #[deprecated(note = "Use `add_viewable` instead")]
-    fn register_viewable<T: BuildView>(&mut self) -> &mut Self {
-        self.add_viewable::<T>()
+    #[test]
+    fn orphaned_views_after_unlinked_despawn() {
+        let mut app = app();
+        app.add_view_linked::<Creature>(false);
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn(Bird).id();
+        let c = app.world_mut().spawn(Monkey).id();
+        app.update();
+        let view =
+            |app: &ViewTestApp, entity| app.world().view::<Creature>(entity).unwrap().entity();
+        let (view_a, view_b, view_c) = (view(&app, a), view(&app, b), view(&app, c));
+        assert!(app.world().orphaned_views::<Creature>().is_empty());
+
+        app.world_mut().despawn(a);
+        app.world_mut().despawn(c);
+        app.update();
+        let mut orphans = app.world().orphaned_views::<Creature>();
+        orphans.sort();
+        let mut expected = vec![view_a, view_c];
+        expected.sort();
+        assert_eq!(orphans, expected);
+
+        app.world_mut().despawn_orphaned_views::<Creature>();
+        assert!(app.world().orphaned_views::<Creature>().is_empty());
+        assert!(app.world().get_entity(view_a).is_err());
+        assert!(app.world().get_entity(view_c).is_err());
+        assert!(app.world().get_entity(view_b).is_ok());
     }
-}
 
-impl RegisterView for App {
-    fn add_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self {
-        self.add_systems(PreUpdate, build_view::<T, V>.after(spawn_view::<T>));
-        let mut viewables = self
-            .world_mut()
-            .get_resource_or_insert_with(Viewables::default);
-        if !viewables.is_viewable_kind::<T>() {
-            viewables.add_kind::<T>();
-            self.add_systems(PreUpdate, spawn_view::<T>.after(CheckSystems));
-            self.add_systems(Last, despawn_view::<T>);
+    #[test]
+    fn viewable_config_configures_view() {
+        #[derive(Component)]
+        struct Lamp;
+
+        #[derive(Component, Clone, Copy, Default)]
+        struct LampConfig {
+            brightness: u32,
         }
-        self
-    }
-}
 
-/// Trait used to spawn a [`View`] [`Entity`] for an [`Instance`] of [`Kind`] `T`.
-pub trait BuildView<T: Kind = Self>: Kind {
-    /// Called when a new [`Instance`] of [`Kind`] `T` is spawned without a [`View`].
-    ///
-    /// Remember to register this type using [`RegisterView`] for this to happen.
-    fn build(_world: &World, _object: Object<T>, view: ViewCommands<T>);
-}
+        #[derive(Component)]
+        struct Light(u32);
 
-pub type ViewCommands<'a, T> = InstanceCommands<'a, View<T>>;
+        impl BuildView for Lamp {
+            fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+                let config = world
+                    .get::<LampConfig>(object.entity())
+                    .copied()
+                    .unwrap_or_default();
+                view.insert(Light(config.brightness));
+            }
+        }
 
-/// [`Component`] of an [`Entity`] associated with a [`View`].
-#[derive(Component)]
-pub struct Viewable<T: Kind> {
-    view: Instance<View<T>>,
-}
+        let mut app = ViewTestApp::with_viewable::<Lamp>();
+        app.add_plugins(MinimalPlugins);
+        let bright = app
+            .world_mut()
+            .spawn((Lamp, LampConfig { brightness: 9 }))
+            .id();
+        let plain = app.world_mut().spawn(Lamp).id();
+        app.update();
 
-impl<T: Kind> Viewable<T> {
-    fn new(view: Instance<View<T>>) -> Self {
-        Self { view }
+        let light = |entity| {
+            let view = app.world().view::<Lamp>(entity).unwrap().entity();
+            app.world().get::<Light>(view).unwrap().0
+        };
+        assert_eq!(light(bright), 9);
+        assert_eq!(light(plain), 0);
     }
-}
 
-#[deprecated(note = "Use `Viewable` instead")]
-pub type Model<T> = Viewable<T>;
+    #[test]
+    fn views_built_for_matching_subset() {
+        #[derive(Component)]
+        struct Nearby;
 
-impl<T: Kind> Viewable<T> {
-    /// Returns the [`View`] [`Instance`] associated with this [`Viewable`].
-    pub fn view(&self) -> Instance<View<T>> {
-        self.view
+        let mut app = app();
+        app.add_view_lazy::<Creature>();
+        let near = app.world_mut().spawn((Bird, Nearby)).id();
+        let far = app.world_mut().spawn(Bird).id();
+        app.update();
+        assert!(!app.world().has_view::<Creature>(near));
+
+        queue(&mut app, build_views_matching::<Creature, With<Nearby>>);
+        app.update();
+        assert!(app.world().has_view::<Creature>(near));
+        assert!(!app.world().has_view::<Creature>(far));
     }
-}
 
-/// [`Component`] of an [`Entity`] associated with a [`Viewable`].
-#[derive(Component)]
-pub struct View<T: Kind> {
-    viewable: Instance<T>,
-}
+    #[test]
+    fn view_resolves_its_viewable() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn(Monkey).id();
+        app.update();
 
-impl<T: Kind> View<T> {
-    #[deprecated(note = "Use `viewable` instead")]
-    pub fn model(&self) -> Instance<T> {
-        self.viewable
+        for entity in [a, b] {
+            let view = app.world().view::<Creature>(entity).unwrap();
+            let viewable = app
+                .world()
+                .get::<View<Creature>>(view.entity())
+                .unwrap()
+                .viewable();
+            assert_eq!(viewable.entity(), entity);
+        }
     }
 
-    /// Returns the associated viewable entity.
-    pub fn viewable(&self) -> Instance<T> {
-        self.viewable
+    #[test]
+    fn build_backlog_shrinks_while_throttled() {
+        let mut app = app();
+        app.add_view_time_budget::<Creature>(Duration::ZERO);
+        for _ in 0..3 {
+            app.world_mut().spawn(Bird);
+        }
+        app.world_mut().spawn(Monkey);
+
+        let mut backlog = vec![app.world_mut().build_backlog::<Creature>()];
+        for _ in 0..4 {
+            app.update();
+            backlog.push(app.world_mut().build_backlog::<Creature>());
+        }
+        assert_eq!(backlog, [4, 3, 2, 1, 0]);
+        assert_eq!(app.world_mut().total_build_backlog(), 0);
     }
-}
 
-#[derive(Bundle)]
-struct ViewBundle<T: Kind> {
-    view: View<T>,
-    unload: Unload,
-}
+    #[test]
+    fn destroyed_event_before_despawn() {
+        #[derive(Resource, Default)]
+        struct Destroyed(Vec<(bool, bool)>);
 
-impl<T: Kind> ViewBundle<T> {
-    pub fn new(viewable: impl Into<Instance<T>>) -> Self {
-        Self {
-            view: View {
-                viewable: viewable.into(),
+        let mut app = app();
+        app.init_resource::<Destroyed>().add_observer(
+            |trigger: Trigger<ViewDestroyed<Creature>>,
+             views: Query<(), With<View<Creature>>>,
+             birds: Query<(), With<Bird>>,
+             mut destroyed: ResMut<Destroyed>| {
+                let event = trigger.event();
+                destroyed
+                    .0
+                    .push((views.contains(event.view), birds.contains(event.viewable)));
             },
-            unload: Unload,
-        }
+        );
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap();
+        // SAFE: `bird` was just spawned as a `Bird`.
+        let bird_instance = unsafe { Instance::<Creature>::from_entity_unchecked(bird) };
+
+        queue(&mut app, |commands| {
+            despawn_viewable(bird_instance, commands)
+        });
+        assert_eq!(app.world().resource::<Destroyed>().0, [(true, true)]);
+        assert!(app.world().get_entity(bird).is_err());
+        assert!(app.world().get_entity(view.entity()).is_err());
     }
-}
 
-impl<T: Kind> KindBundle for ViewBundle<T> {
-    type Kind = View<T>;
-}
+    #[test]
+    fn finalizer_sees_views_built_in_frame() {
+        #[derive(Component)]
+        struct Fish;
 
-/// A [`Resource`] which contains a mapping of all viewable entities to their views.
-///
-/// # Usage
-///
-/// Typically, you want to access viewables or views using [`Viewable`] and [`View`] components.
-/// However, in some cases it may be needed to access **all** views for a given viewable.
-/// This [`Resource`] provides an interface for this specific purpose.
-#[derive(Resource, Default)]
-pub struct Viewables {
-    entities: HashMap<Entity, HashSet<Entity>>,
-    kinds: HashMap<TypeId, HashSet<Entity>>,
-    views: HashMap<Entity, Entity>,
-}
+        #[derive(Component)]
+        struct Scales;
 
-impl Viewables {
-    pub fn contains(&self, entity: Entity) -> bool {
-        self.entities.contains_key(&entity)
-    }
+        #[derive(Resource, Default)]
+        struct Finalized(Vec<usize>);
 
-    /// Iterates over all viewed [`Viewable`] entities.
-    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
-        self.entities.keys().copied()
-    }
+        impl BuildView for Fish {
+            fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+                view.insert(Scales);
+            }
+        }
 
-    pub fn is_viewable_kind<T: Kind>(&self) -> bool {
-        self.kinds.contains_key(&TypeId::of::<T>())
-    }
+        let mut app = ViewTestApp::with_viewable::<Fish>();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Finalized>()
+            .add_view_finalizer::<Fish, _>(
+                |views: NewViews<Fish>,
+                 scales: Query<(), With<Scales>>,
+                 mut finalized: ResMut<Finalized>| {
+                    assert!(views.iter().all(|(view, _)| scales.contains(view.entity())));
+                    finalized.0.push(views.iter().count());
+                },
+            );
+        for _ in 0..3 {
+            app.world_mut().spawn(Fish);
+        }
+        app.update();
+        app.update();
+        app.world_mut().spawn(Fish);
+        app.update();
 
-    pub fn is_view(&self, entity: Entity) -> bool {
-        self.views.contains_key(&entity)
+        assert_eq!(app.world().resource::<Finalized>().0, [3, 1]);
     }
 
-    pub fn is_view_of_kind<T: Kind>(&self, entity: Entity) -> bool {
-        let Some(viewable) = self.views.get(&entity) else {
-            return false;
+    #[test]
+    fn has_view_before_and_after_build() {
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        let has_view = |app: &ViewTestApp| {
+            (
+                app.world().has_view::<Creature>(bird),
+                app.world()
+                    .resource::<Viewables>()
+                    .has_view::<Creature>(bird),
+            )
         };
-        self.kinds
-            .get(&std::any::TypeId::of::<T>())
-            .is_some_and(|entities| entities.contains(viewable))
+        assert_eq!(has_view(&app), (false, false));
+
+        app.update();
+        assert_eq!(has_view(&app), (true, true));
+        assert!(!app.world().has_view::<Monkey>(bird));
     }
 
-    /// Iterates over all views for a given [`Viewable`] [`Entity`].
-    pub fn views(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
-        self.entities
-            .get(&entity)
+    #[test]
+    fn viewables_of_views_in_bulk() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Bird).id();
+        let b = app.world_mut().spawn(Monkey).id();
+        let other = app.world_mut().spawn_empty().id();
+        let dead = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(dead);
+        app.update();
+        let view_a = app.world().view::<Creature>(a).unwrap().entity();
+        let view_b = app.world().view::<Creature>(b).unwrap().entity();
+
+        let viewables: Vec<Option<Entity>> = app
+            .world()
+            .viewables_of_views::<Creature>(&[view_b, other, a, dead, view_a])
             .into_iter()
-            .flat_map(|views| views.iter().copied())
+            .map(|viewable| viewable.map(|viewable| viewable.entity()))
+            .collect();
+        assert_eq!(viewables, [Some(b), None, None, None, Some(a)]);
     }
 
-    fn add_kind<T: Kind>(&mut self) {
-        self.kinds.insert(TypeId::of::<T>(), HashSet::default());
-    }
+    #[test]
+    fn children_changed_runs_for_view() {
+        #[derive(Resource, Default)]
+        struct Changed(Vec<Entity>);
 
-    fn add<T: Kind>(&mut self, entity: Entity, view: Instance<View<T>>) {
-        self.entities
-            .entry(entity)
-            .or_default()
-            .insert(view.entity());
-        self.kinds
-            .get_mut(&TypeId::of::<T>())
-            .expect("kind must be registered as viewable")
-            .insert(entity);
-        let previous = self.views.insert(view.entity(), entity);
-        debug_assert!(previous.is_none());
-    }
+        let mut app = app();
+        app.init_resource::<Changed>()
+            .add_view_on_children_changed::<Creature, _>(
+                |In(view): In<Instance<View<Creature>>>, mut changed: ResMut<Changed>| {
+                    changed.0.push(view.entity());
+                },
+            );
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        app.update();
+        assert!(app.world().resource::<Changed>().0.is_empty());
 
-    fn remove<T: Kind>(&mut self, entity: Entity, view: Instance<View<T>>) {
-        let views = self.entities.get_mut(&entity).unwrap();
-        views.remove(&view.entity());
-        if views.is_empty() {
-            self.entities.remove(&entity);
-        }
-        let kinds = self.kinds.get_mut(&TypeId::of::<T>()).unwrap();
-        kinds.remove(&view.entity());
-        self.views.remove(&view.entity());
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+        let child = app.world_mut().spawn_empty().id();
+        app.world_mut().entity_mut(view).add_child(child);
+        app.update();
+        assert_eq!(app.world().resource::<Changed>().0, [view]);
+
+        app.update();
+        assert_eq!(app.world().resource::<Changed>().0, [view]);
     }
-}
 
-fn spawn_view<T: Kind>(objects: Objects<T, Without<Viewable<T>>>, mut commands: Commands) {
-    for object in objects.iter() {
-        let view = commands.spawn_instance(ViewBundle::new(object)).instance();
-        let entity = object.entity();
-        commands.queue(move |world: &mut World| {
-            world.resource_mut::<Viewables>().add(entity, view);
+    #[test]
+    fn preview_is_committed_as_view() {
+        #[derive(Component)]
+        struct Ghost;
+
+        let mut app = app();
+        let mut preview = None;
+        queue(&mut app, |commands| {
+            let mut view = spawn_preview::<Creature>(commands);
+            view.insert(Ghost);
+            preview = Some(view.instance());
         });
-        commands.entity(entity).insert(Viewable::new(view));
-        debug!("{view:?} spawned for {entity:?}");
-    }
-}
+        let preview = preview.unwrap();
+        app.update();
+        assert!(app.world().get::<PreviewView>(preview.entity()).is_some());
+        assert!(app.world().orphaned_views::<Creature>().is_empty());
 
-fn build_view<T: Kind, S: BuildView<T>>(
-    objects: Objects<
-        T,
-        (
-            Or<(
-                Added<Viewable<T>>,
-                (With<Viewable<T>>, Without<Viewable<S>>),
-            )>,
-            S::Filter,
-        ),
-    >,
-    world: &World,
-    mut commands: Commands,
-) {
-    for object in objects.iter() {
-        let base_viewable = world.get::<Viewable<T>>(object.entity()).unwrap();
-        let base_view = base_viewable.view();
+        let bird = app.world_mut().spawn(Bird).id();
+        // SAFE: `bird` was just spawned as a `Bird`.
+        let bird_instance = unsafe { Instance::<Creature>::from_entity_unchecked(bird) };
+        queue(&mut app, |commands| {
+            commit_preview(preview, bird_instance, commands)
+        });
+        app.update();
 
-        // SAFE: `View<S>` will be inserted later.
-        let view = unsafe { base_view.cast_into_unchecked::<View<S>>() };
+        assert_eq!(app.world().view::<Creature>(bird), Some(preview));
+        assert!(app.world().get::<PreviewView>(preview.entity()).is_none());
+        assert!(app.world().get::<Ghost>(preview.entity()).is_some());
+        assert_eq!(app.world().view_count::<Creature>(), 1);
+        assert_view_invariants::<Creature>(app.world());
+    }
 
-        commands
-            .entity(object.entity())
-            .insert_if_new(Viewable::<S>::new(view));
+    #[test]
+    fn shared_view_child_survives_view_despawn() {
+        let mut app = app();
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+        let shared = app.world_mut().spawn(SharedViewChild).id();
+        let owned = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .entity_mut(view)
+            .add_children(&[shared, owned]);
 
-        commands
-            .entity(base_view.entity())
-            .insert_if_new(View::<S> {
-                // SAFE: `S::Filter` applied to query
-                viewable: unsafe { object.instance().cast_into_unchecked() },
-            });
+        app.world_mut().despawn(bird);
+        app.update();
+        assert!(app.world().get_entity(view).is_err());
+        assert!(app.world().get_entity(shared).is_ok());
+        assert!(app.world().get::<Parent>(shared).is_none());
+        assert!(app.world().get_entity(owned).is_err());
+    }
 
-        S::build(world, object, commands.instance(base_view));
+    #[test]
+    fn view_lookup_after_churn() {
+        let mut app = app();
+        let mut live: Vec<Entity> = Vec::new();
+        let mut dead: Vec<Entity> = Vec::new();
+        let mut unkinded: Vec<Entity> = Vec::new();
+        for cycle in 0..4 {
+            if cycle % 2 == 0 && !live.is_empty() {
+                let entity = live.remove(0);
+                app.world_mut().despawn(entity);
+                dead.push(entity);
+            }
+            live.extend((0..3).map(|_| app.world_mut().spawn(Bird).id()));
+            if let Some(entity) = live.pop() {
+                app.world_mut().despawn(entity);
+                dead.push(entity);
+            }
+            if cycle % 2 == 1 {
+                let entity = live.remove(0);
+                app.world_mut().entity_mut(entity).remove::<Bird>();
+                unkinded.push(entity);
+            }
+            app.update();
+
+            for &entity in &live {
+                assert!(app.world().view::<Creature>(entity).is_some());
+            }
+            for &entity in dead.iter().chain(&unkinded) {
+                assert!(app.world().view::<Creature>(entity).is_none());
+            }
+            assert_eq!(app.world().view_count::<Creature>(), live.len());
+        }
     }
-}
 
-fn despawn_view<T: Kind>(
-    views: Query<InstanceRef<View<T>>>,
-    query: Query<(), T::Filter>,
-    mut commands: Commands,
-) {
-    for view in views.iter() {
-        let viewable = view.viewable();
-        let view = view.instance();
-        if query.get(viewable.entity()).is_err() {
-            commands.queue(move |world: &mut World| {
-                if let Ok(mut entity) = world.get_entity_mut(viewable.entity()) {
-                    entity.remove::<Viewable<T>>();
-                }
-                if let Ok(view_entity) = world.get_entity_mut(view.entity()) {
-                    view_entity.despawn_recursive();
-                }
-                world
-                    .resource_mut::<Viewables>()
-                    .remove(viewable.entity(), view);
-                debug!("{view:?} despawned for {viewable:?}");
-            });
+    #[test]
+    fn linked_views_follow_setting() {
+        for linked in [true, false] {
+            let mut app = app();
+            app.add_view_linked::<Creature>(linked);
+            let bird = app.world_mut().spawn(Bird).id();
+            app.update();
+            let view = app.world().view::<Creature>(bird).unwrap().entity();
+
+            app.world_mut().despawn(bird);
+            app.update();
+            assert_eq!(app.world().get_entity(view).is_ok(), !linked);
+            assert!(app.world().get::<View<Creature>>(view).is_none());
         }
     }
-}
 
-/// Despawns the current [`View`] associated with this [`Viewable`] and rebuilds a new one.
-///
-/// # Example
-/// ```
-/// # use bevy::prelude::*;
-/// # use moonshine_core::prelude::*;
-/// # use moonshine_view::prelude::*;
-///
-/// #[derive(Component)]
-/// enum Shape {
-///     Square,
-///     Circle,
-/// }
-///
-/// impl BuildView for Shape {
-///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
-///         let shape = world.get::<Shape>(object.entity());
-///         // ...
-///     }
-/// }
-///
-/// fn rebuild_shape_views(query: Query<InstanceRef<Viewable<Shape>>>, mut commands: Commands) {
-///     for viewable in query.iter() {
-///         moonshine_view::rebuild(viewable, &mut commands);
-///     }
-/// }
-/// ```
-pub fn rebuild<T: Kind>(viewable: InstanceRef<Viewable<T>>, commands: &mut Commands) {
-    let entity = viewable.entity();
-    let view = viewable.view();
-    commands.entity(view.entity()).despawn_recursive();
-    commands.queue(move |world: &mut World| {
-        world.resource_mut::<Viewables>().remove(entity, view);
-    });
-    commands.entity(entity).remove::<Viewable<T>>();
+    #[test]
+    fn event_propagates_from_view_to_viewable() {
+        #[derive(Component, Clone)]
+        struct Clicked;
+
+        impl Event for Clicked {
+            type Traversal = &'static View<Creature>;
+            const AUTO_PROPAGATE: bool = true;
+        }
+
+        #[derive(Resource, Default)]
+        struct Targets(Vec<Entity>);
+
+        let mut app = app();
+        app.init_resource::<Targets>().add_observer(
+            |trigger: Trigger<Clicked>, mut targets: ResMut<Targets>| {
+                targets.0.push(trigger.entity());
+            },
+        );
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Creature>(bird).unwrap().entity();
+
+        app.world_mut().trigger_targets(Clicked, view);
+        app.world_mut().flush();
+        assert_eq!(app.world().resource::<Targets>().0, [view, bird]);
+    }
 }
@@ -0,0 +1,191 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{View, Viewable};
+
+type SpawnLod<L> = Box<dyn Fn(&L, &mut WorldChildBuilder) + Send + Sync>;
+
+/// A [`Resource`] which stores the function added using [`add_view_lod`](crate::RegisterView::add_view_lod).
+#[derive(Resource)]
+pub struct ViewLod<T: Kind, L: Component> {
+    spawn: SpawnLod<L>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind, L: Component> ViewLod<T, L> {
+    pub fn new(spawn: impl Fn(&L, &mut WorldChildBuilder) + Send + Sync + 'static) -> Self {
+        Self {
+            spawn: Box::new(spawn),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A [`Component`] which stores the level of detail `L` of a view, and the children spawned for it.
+#[derive(Component)]
+pub struct ViewLodChildren<T: Kind, L: Component> {
+    lod: L,
+    children: Vec<Entity>,
+    marker: PhantomData<T>,
+}
+
+/// Replaces the level of detail children of each view of [`Kind`] `T` whose viewable level of detail `L` differs
+/// from the one its children were spawned for, including new views.
+#[allow(clippy::type_complexity)]
+pub fn update_view_lod<T: Kind, L: Component + PartialEq + Clone>(
+    viewables: Query<(&L, &Viewable<T>), Or<(Changed<L>, Changed<Viewable<T>>)>>,
+    views: Query<Option<&ViewLodChildren<T, L>>, With<View<T>>>,
+    mut commands: Commands,
+) {
+    for (lod, viewable) in viewables.iter() {
+        let view = viewable.view();
+        let Ok(current) = views.get(view.entity()) else {
+            continue;
+        };
+        if current.is_some_and(|current| current.lod == *lod) {
+            continue;
+        }
+        let lod = lod.clone();
+        commands.queue(move |world: &mut World| set_view_lod::<T, L>(world, view, lod));
+    }
+}
+
+fn set_view_lod<T: Kind, L: Component>(world: &mut World, view: Instance<View<T>>, lod: L) {
+    let Ok(mut view_entity) = world.get_entity_mut(view.entity()) else {
+        return;
+    };
+    if let Some(previous) = view_entity.take::<ViewLodChildren<T, L>>() {
+        for child in previous.children {
+            if let Ok(child) = world.get_entity_mut(child) {
+                child.despawn_recursive();
+            }
+        }
+    }
+    let existing: Vec<Entity> = world
+        .get::<Children>(view.entity())
+        .into_iter()
+        .flatten()
+        .copied()
+        .collect();
+    world.resource_scope(|world, spawn: Mut<ViewLod<T, L>>| {
+        world
+            .entity_mut(view.entity())
+            .with_children(|parent| (spawn.spawn)(&lod, parent));
+    });
+    let children = world
+        .get::<Children>(view.entity())
+        .into_iter()
+        .flatten()
+        .copied()
+        .filter(|child| !existing.contains(child))
+        .collect();
+    world
+        .entity_mut(view.entity())
+        .insert(ViewLodChildren::<T, L> {
+            lod,
+            children,
+            marker: PhantomData,
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Unit;
+
+    #[derive(Component, PartialEq, Clone)]
+    struct Lod(u8);
+
+    #[derive(Component)]
+    struct High;
+
+    #[derive(Component)]
+    struct Low;
+
+    #[derive(Component)]
+    struct Base;
+
+    impl BuildView for Unit {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.with_children(|view| {
+                view.spawn(Base);
+            });
+        }
+    }
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Unit>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_lod::<Unit, Lod>(|lod, parent| {
+                if lod.0 == 0 {
+                    parent.spawn(High);
+                    parent.spawn(High);
+                } else {
+                    parent.spawn(Low);
+                }
+            });
+        app
+    }
+
+    fn count_children<C: Component>(app: &ViewTestApp, view: Entity) -> usize {
+        app.world()
+            .get::<Children>(view)
+            .into_iter()
+            .flatten()
+            .filter(|&&child| app.world().get::<C>(child).is_some())
+            .count()
+    }
+
+    #[test]
+    fn lod_swap_replaces_children() {
+        let mut app = app();
+        let unit = app.world_mut().spawn((Unit, Lod(0))).id();
+        app.update();
+        let view = app.world().view::<Unit>(unit).unwrap().entity();
+        assert_eq!(count_children::<High>(&app, view), 2);
+        assert_eq!(count_children::<Low>(&app, view), 0);
+        assert_eq!(count_children::<Base>(&app, view), 1);
+
+        app.world_mut().get_mut::<Lod>(unit).unwrap().0 = 1;
+        app.update();
+        assert_eq!(count_children::<High>(&app, view), 0);
+        assert_eq!(count_children::<Low>(&app, view), 1);
+        assert_eq!(count_children::<Base>(&app, view), 1);
+        let high = app.world_mut().query::<&High>().iter(app.world()).count();
+        assert_eq!(high, 0);
+
+        let low = app
+            .world()
+            .get::<ViewLodChildren<Unit, Lod>>(view)
+            .unwrap()
+            .children[0];
+        app.world_mut().get_mut::<Lod>(unit).unwrap().0 = 1;
+        app.update();
+        let current = app.world().get::<ViewLodChildren<Unit, Lod>>(view).unwrap();
+        assert_eq!(current.children, [low]);
+    }
+
+    #[test]
+    fn duplicate_lod_is_ignored() {
+        let mut app = app();
+        app.add_view_lod::<Unit, Lod>(|_, parent| {
+            parent.spawn(Low);
+        });
+        let unit = app.world_mut().spawn((Unit, Lod(0))).id();
+        app.update();
+        let view = app.world().view::<Unit>(unit).unwrap().entity();
+        assert_eq!(count_children::<High>(&app, view), 2);
+        assert_eq!(count_children::<Low>(&app, view), 0);
+    }
+}
@@ -0,0 +1,301 @@
+#[cfg(feature = "metrics")]
+use std::marker::PhantomData;
+
+#[cfg(feature = "metrics")]
+use bevy_core::FrameCount;
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::View;
+
+/// A [`Resource`] which tracks the population of views of [`Kind`] `T`.
+///
+/// This resource is inserted for each viewable kind when it is registered. Use it in gameplay logic to make
+/// decisions based on the number of views, such as to stop spawning new entities when too many views exist.
+///
+/// A view is counted as built when it is spawned or [adopted](crate::adopt), and as despawned when its
+/// [`View`](crate::View) is removed for any reason, including when the view is rebuilt or unloaded.
+/// [Preview](crate::PreviewView) views are not counted.
+///
+/// # Reset
+///
+/// Counters are never reset automatically. Call [`reset`](Self::reset) to clear [`built`](Self::built),
+/// [`despawned`](Self::despawned) and [`last_build_frame`](Self::last_build_frame), for example at the start
+/// of a level. [`active`](Self::active) is not reset, since it always reflects the current number of views.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shape;
+///
+/// fn spawn_shapes(metrics: Res<ViewMetrics<Shape>>, mut commands: Commands) {
+///     if metrics.active() < 100 {
+///         commands.spawn(Shape);
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(spawn_shapes);
+/// ```
+#[cfg(feature = "metrics")]
+#[derive(Resource)]
+pub struct ViewMetrics<T: Kind> {
+    built: u64,
+    despawned: u64,
+    active: usize,
+    last_build_frame: Option<u32>,
+    marker: PhantomData<T>,
+}
+
+#[cfg(feature = "metrics")]
+impl<T: Kind> Default for ViewMetrics<T> {
+    fn default() -> Self {
+        Self {
+            built: 0,
+            despawned: 0,
+            active: 0,
+            last_build_frame: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<T: Kind> ViewMetrics<T> {
+    /// Returns the total number of views built since the last [`reset`](Self::reset).
+    pub fn built(&self) -> u64 {
+        self.built
+    }
+
+    /// Returns the total number of views despawned since the last [`reset`](Self::reset).
+    pub fn despawned(&self) -> u64 {
+        self.despawned
+    }
+
+    /// Returns the number of views which currently exist.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Returns the [`FrameCount`] at which the last view was built, if any view was built
+    /// since the last [`reset`](Self::reset).
+    pub fn last_build_frame(&self) -> Option<u32> {
+        self.last_build_frame
+    }
+
+    /// Clears all counters, except for [`active`](Self::active).
+    pub fn reset(&mut self) {
+        self.built = 0;
+        self.despawned = 0;
+        self.last_build_frame = None;
+    }
+
+    pub(crate) fn add_built(&mut self, frame: Option<u32>) {
+        self.built += 1;
+        self.active += 1;
+        self.last_build_frame = frame.or(self.last_build_frame);
+    }
+
+    /// Returns `true` if no views remain after this one.
+    pub(crate) fn add_despawned(&mut self) -> bool {
+        self.despawned += 1;
+        let empty = self.active == 1;
+        self.active = self.active.saturating_sub(1);
+        empty
+    }
+}
+
+/// An [`Event`] which is sent when the last view of [`Kind`] `T` is despawned.
+///
+/// This is sent once each time the number of [`active`](ViewMetrics::active) views drops to zero, and not
+/// again until at least one new view is spawned and despawned. Note that [rebuilding](crate::rebuild) the
+/// only view of a kind also despawns it.
+#[cfg(feature = "metrics")]
+#[derive(Event)]
+pub struct NoViewsRemaining<T: Kind>(PhantomData<T>);
+
+#[cfg(feature = "metrics")]
+impl<T: Kind> Default for NoViewsRemaining<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Records a view of [`Kind`] `T` as built in the given [`World`], if its metrics exist.
+#[cfg(feature = "metrics")]
+pub(crate) fn record_built<T: Kind>(world: &mut World) {
+    let frame = world.get_resource::<FrameCount>().map(|frame| frame.0);
+    if let Some(mut metrics) = world.get_resource_mut::<ViewMetrics<T>>() {
+        metrics.add_built(frame);
+    }
+}
+
+/// A [`Resource`] which buffers the views of [`Kind`] `T` despawned during the current update.
+///
+/// This is inserted for each viewable kind when it is registered, and cleared in [`First`](bevy_app::First).
+/// Use it in systems which prefer polling over [observers](crate::ViewDestroyed), such as to maintain an
+/// external spatial index. Like [`ViewMetrics`], a view is considered despawned when its [`View`] is removed
+/// for any reason. [Preview](crate::PreviewView) views are not buffered.
+///
+/// Most views are despawned during [`ViewSystems::Despawn`](crate::ViewSystems::Despawn). To see every view
+/// despawned in an update, read this resource in [`Last`](bevy_app::Last), after that set.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shape;
+///
+/// fn remove_shapes_from_index(despawned: Res<DespawnedViews<Shape>>) {
+///     for (view, viewable) in despawned.iter() {
+///         // ...
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(remove_shapes_from_index);
+/// ```
+#[derive(Resource)]
+pub struct DespawnedViews<T: Kind> {
+    views: Vec<(Instance<View<T>>, Instance<T>)>,
+}
+
+impl<T: Kind> Default for DespawnedViews<T> {
+    fn default() -> Self {
+        Self { views: Vec::new() }
+    }
+}
+
+impl<T: Kind> DespawnedViews<T> {
+    /// Iterates over all despawned views and their viewable instances, in order of despawn.
+    ///
+    /// The viewable instances may no longer exist.
+    pub fn iter(&self) -> impl Iterator<Item = (Instance<View<T>>, Instance<T>)> + '_ {
+        self.views.iter().copied()
+    }
+
+    /// Returns the number of despawned views.
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns `true` if no views were despawned.
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    pub(crate) fn add(&mut self, view: Instance<View<T>>, viewable: Instance<T>) {
+        self.views.push((view, viewable));
+    }
+}
+
+/// Clears the [`DespawnedViews<T>`] of the previous update.
+pub fn clear_despawned_views<T: Kind>(mut despawned: ResMut<DespawnedViews<T>>) {
+    despawned.views.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins);
+        app
+    }
+
+    #[cfg(feature = "metrics")]
+    fn counts(app: &ViewTestApp) -> (u64, u64, usize) {
+        let metrics = app.world().resource::<ViewMetrics<Node>>();
+        (metrics.built(), metrics.despawned(), metrics.active())
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn metrics_track_views() {
+        let mut app = app();
+        let entity = app.world_mut().spawn(Node).id();
+        app.world_mut().spawn(Node);
+        app.update();
+        assert_eq!(counts(&app), (2, 0, 2));
+        let frame = app.world().resource::<FrameCount>().0;
+        let metrics = app.world().resource::<ViewMetrics<Node>>();
+        assert!(metrics.last_build_frame().is_some_and(|last| last <= frame));
+
+        app.world_mut().despawn(entity);
+        app.update();
+        assert_eq!(counts(&app), (2, 1, 1));
+
+        app.world_mut().resource_mut::<ViewMetrics<Node>>().reset();
+        assert!(app
+            .world()
+            .resource::<ViewMetrics<Node>>()
+            .last_build_frame()
+            .is_none());
+        app.world_mut().spawn(Node);
+        app.update();
+        assert_eq!(counts(&app), (1, 0, 2));
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn no_views_remaining_is_sent_once() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Node).id();
+        let b = app.world_mut().spawn(Node).id();
+        app.update();
+
+        app.world_mut().despawn(a);
+        app.update();
+        let events = app.world().resource::<Events<NoViewsRemaining<Node>>>();
+        assert!(events.is_empty());
+
+        app.world_mut().despawn(b);
+        app.update();
+        app.update();
+        let events = app.world().resource::<Events<NoViewsRemaining<Node>>>();
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn despawned_views_are_buffered_for_one_update() {
+        let mut app = app();
+        let entities: Vec<Entity> = (0..3).map(|_| app.world_mut().spawn(Node).id()).collect();
+        let kept = app.world_mut().spawn(Node).id();
+        app.update();
+        assert!(app.world().resource::<DespawnedViews<Node>>().is_empty());
+
+        let mut expected: Vec<(Entity, Entity)> = entities
+            .iter()
+            .map(|&entity| (app.world().view::<Node>(entity).unwrap().entity(), entity))
+            .collect();
+        for &entity in &entities {
+            app.world_mut().despawn(entity);
+        }
+        app.update();
+        let mut despawned: Vec<(Entity, Entity)> = app
+            .world()
+            .resource::<DespawnedViews<Node>>()
+            .iter()
+            .map(|(view, viewable)| (view.entity(), viewable.entity()))
+            .collect();
+        despawned.sort();
+        expected.sort();
+        assert_eq!(despawned, expected);
+
+        app.update();
+        assert!(app.world().resource::<DespawnedViews<Node>>().is_empty());
+        assert!(app.world().has_view::<Node>(kept));
+    }
+}
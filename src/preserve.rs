@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// A [`Resource`] which stores a function for each component preserved using
+/// [`add_view_preserve`](crate::RegisterView::add_view_preserve).
+#[derive(Resource)]
+pub struct ViewPreservers<T: Kind> {
+    preservers: Vec<fn(&mut World, Entity, Entity)>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> Default for ViewPreservers<T> {
+    fn default() -> Self {
+        Self {
+            preservers: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Kind> ViewPreservers<T> {
+    pub fn add<C: Component + Clone>(&mut self) {
+        self.preservers.push(preserve::<T, C>);
+    }
+}
+
+/// A [`Component`] which stores a preserved component of a view on its viewable entity while it is rebuilt.
+#[derive(Component)]
+pub struct Preserved<T: Kind, C: Component>(C, PhantomData<T>);
+
+/// Stashes all preserved components of the given view on its viewable entity, before the view is despawned.
+pub fn preserve_view<T: Kind>(world: &mut World, viewable: Entity, view: Entity) {
+    let Some(preservers) = world.get_resource::<ViewPreservers<T>>() else {
+        return;
+    };
+    for preserve in preservers.preservers.clone() {
+        preserve(world, viewable, view);
+    }
+}
+
+fn preserve<T: Kind, C: Component + Clone>(world: &mut World, viewable: Entity, view: Entity) {
+    let Some(component) = world.get::<C>(view).cloned() else {
+        return;
+    };
+    if let Ok(mut entity) = world.get_entity_mut(viewable) {
+        entity.insert(Preserved::<T, C>(component, PhantomData));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn restore_preserved<T: Kind, C: Component + Clone>(
+    query: Query<(Entity, &Viewable<T>, &Preserved<T, C>), Added<Viewable<T>>>,
+    mut commands: Commands,
+) {
+    for (entity, viewable, Preserved(component, _)) in query.iter() {
+        commands
+            .entity(viewable.view().entity())
+            .insert(component.clone());
+        commands.entity(entity).remove::<Preserved<T, C>>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_ecs::system::RunSystemOnce;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component, Clone)]
+    struct Highlight(u8);
+
+    #[derive(Component)]
+    struct Hover;
+
+    #[test]
+    fn preserved_component_survives_rebuild() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_preserve::<Node, Highlight>();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        app.world_mut()
+            .entity_mut(view)
+            .insert((Highlight(3), Hover));
+
+        app.world_mut()
+            .run_system_once(
+                |query: Query<InstanceRef<Viewable<Node>>>, mut commands: Commands| {
+                    for viewable in query.iter() {
+                        crate::rebuild(viewable, &mut commands);
+                    }
+                },
+            )
+            .unwrap();
+        app.update();
+        app.update();
+
+        let rebuilt = app.world().view::<Node>(node).unwrap().entity();
+        assert_ne!(rebuilt, view);
+        assert_eq!(app.world().get::<Highlight>(rebuilt).unwrap().0, 3);
+        assert!(app.world().get::<Hover>(rebuilt).is_none());
+        assert!(app
+            .world()
+            .get::<Preserved<Node, Highlight>>(node)
+            .is_none());
+    }
+}
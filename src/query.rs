@@ -0,0 +1,374 @@
+use bevy_ecs::component::Tick;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::{QueryData, QueryFilter, ROQueryItem};
+use bevy_ecs::system::{SystemChangeTick, SystemParam};
+use bevy_utils::HashMap;
+
+use moonshine_core::prelude::*;
+
+use crate::{PreviewView, View, ViewDormant, ViewUnload, Viewable};
+
+/// A [`SystemParam`] used to query the [`View`] entity of a viewable entity directly.
+///
+/// This is equivalent to querying [`Viewable<T>`] on the viewable entity, followed by querying `D` on its view.
+///
+/// Note that this is a [`SystemParam`] rather than a [`QueryData`] which may be used within a query tuple,
+/// such as `Query<(&Position, ViewOf<&Transform>)>`. A [`QueryData`] may only access the components of the
+/// entity it matches, so it cannot follow [`Viewable<T>`] to the view. Instead, use this alongside the query of
+/// the viewable entities, and access each view using its viewable [`Entity`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// #[derive(Component)]
+/// struct Position(Vec3);
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+///         view.insert(Transform::default());
+///     }
+/// }
+///
+/// fn view_bird_position(
+///     birds: Query<(Entity, &Position), (With<Bird>, Changed<Position>)>,
+///     mut views: ViewQuery<Bird, &mut Transform>,
+/// ) {
+///     for (bird, position) in birds.iter() {
+///         if let Some(mut transform) = views.get_mut(bird) {
+///             transform.translation = position.0;
+///         }
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(view_bird_position);
+/// ```
+#[derive(SystemParam)]
+pub struct ViewQuery<'w, 's, T: Kind, D: QueryData + 'static, F: QueryFilter + 'static = ()> {
+    viewables: Query<'w, 's, &'static Viewable<T>>,
+    views: Query<'w, 's, D, (With<View<T>>, F)>,
+}
+
+impl<T: Kind, D: QueryData + 'static, F: QueryFilter + 'static> ViewQuery<'_, '_, T, D, F> {
+    /// Returns the read-only query item of the [`View`] associated with the given viewable [`Entity`].
+    ///
+    /// Returns `None` if the entity is not viewable, or if its view does not match the query.
+    pub fn get(&self, viewable: Entity) -> Option<ROQueryItem<'_, D>> {
+        let view = self.viewables.get(viewable).ok()?.view();
+        self.views.get(view.entity()).ok()
+    }
+
+    /// Returns the query item of the [`View`] associated with the given viewable [`Entity`].
+    ///
+    /// Returns `None` if the entity is not viewable, or if its view does not match the query.
+    pub fn get_mut(&mut self, viewable: Entity) -> Option<D::Item<'_>> {
+        let view = self.viewables.get(viewable).ok()?.view();
+        self.views.get_mut(view.entity()).ok()
+    }
+
+    /// Returns `true` if the given viewable [`Entity`] has a [`View`] which matches the query.
+    pub fn contains(&self, viewable: Entity) -> bool {
+        self.viewables
+            .get(viewable)
+            .is_ok_and(|viewable| self.views.contains(viewable.view().entity()))
+    }
+}
+
+/// A [`SystemParam`] used to iterate over views of [`Kind`] `T` whose viewable entities match the filter `F`.
+///
+/// This is equivalent to querying [`Viewable<T>`] on viewable entities which match `F`, and following each to its view.
+/// Viewable entities without a view are skipped.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// #[derive(Component)]
+/// struct Selected;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn highlight_selected_birds(views: ViewsWhere<Bird, With<Selected>>) {
+///     for view in views.iter() {
+///         // ...
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(highlight_selected_birds);
+/// ```
+#[derive(SystemParam)]
+pub struct ViewsWhere<'w, 's, T: Kind, F: QueryFilter + 'static> {
+    viewables: Query<'w, 's, &'static Viewable<T>, F>,
+}
+
+impl<T: Kind, F: QueryFilter + 'static> ViewsWhere<'_, '_, T, F> {
+    /// Iterates over the views of all viewable entities which match the filter.
+    pub fn iter(&self) -> impl Iterator<Item = Instance<View<T>>> + '_ {
+        self.viewables.iter().map(Viewable::view)
+    }
+
+    /// Returns the view of the given viewable [`Entity`], if it matches the filter.
+    pub fn get(&self, viewable: Entity) -> Option<Instance<View<T>>> {
+        self.viewables.get(viewable).ok().map(Viewable::view)
+    }
+
+    /// Returns `true` if no viewable entity with a view matches the filter.
+    pub fn is_empty(&self) -> bool {
+        self.viewables.is_empty()
+    }
+}
+
+/// A [`SystemParam`] used to iterate over views of [`Kind`] `T` which were spawned since the system last ran.
+///
+/// Views are spawned and built during [`PreUpdate`](bevy_app::PreUpdate), so any system which runs after
+/// [`ViewSystems::Build`](crate::ViewSystems::Build) sees each new view exactly once, after it is built.
+/// [`PreviewView`] entities are excluded.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn init_bird_views(views: NewViews<Bird>) {
+///     for (view, viewable) in views.iter() {
+///         // ...
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(init_bird_views);
+/// ```
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct NewViews<'w, 's, T: Kind> {
+    views: Query<'w, 's, InstanceRef<'static, View<T>>, (Added<View<T>>, Without<PreviewView>)>,
+}
+
+impl<T: Kind> NewViews<'_, '_, T> {
+    /// Iterates over all new views and their viewable instances.
+    pub fn iter(&self) -> impl Iterator<Item = (Instance<View<T>>, Instance<T>)> + '_ {
+        self.views
+            .iter()
+            .map(|view| (view.instance(), view.viewable()))
+    }
+
+    /// Returns `true` if there are no new views.
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+}
+
+/// A [`SystemParam`] used to iterate over views of [`Kind`] `T`, spawning any which do not exist yet.
+///
+/// This is intended to be used with [`add_view_lazy`](crate::RegisterView::add_view_lazy), so that views
+/// are only spawned at the point of use, rather than during [`ViewSystems::Spawn`](crate::ViewSystems::Spawn).
+///
+/// # Command Flush
+///
+/// Missing views are spawned using [`Commands`]. This means any view yielded for the first time does not exist
+/// until the system's commands are applied, and it is only built during the next
+/// [`ViewSystems::Build`](crate::ViewSystems::Build). Only the [`Instance`] of such views should be stored.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shape;
+///
+/// impl BuildView for Shape {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// fn inspect_shapes(mut views: LazyView<Shape>) {
+///     for (shape, view) in views.iter() {
+///         // ...
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_viewable::<Shape>()
+///     .add_view_lazy::<Shape>()
+///     .add_systems(Update, inspect_shapes);
+/// ```
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct LazyView<'w, 's, T: Kind> {
+    instances: Query<
+        'w,
+        's,
+        (
+            Instance<T>,
+            Option<&'static Viewable<T>>,
+            Has<Save>,
+            Option<&'static ViewUnload>,
+        ),
+        (<T as Kind>::Filter, Without<ViewDormant<T>>),
+    >,
+    pending: Local<'s, (Option<Tick>, HashMap<Entity, Instance<View<T>>>)>,
+    ticks: SystemChangeTick,
+    commands: Commands<'w, 's>,
+}
+
+impl<T: Kind> LazyView<'_, '_, T> {
+    /// Iterates over all viewable instances of `T` and their views, spawning any missing views.
+    ///
+    /// Views spawned by a previous call during the same system run are reused.
+    pub fn iter(&mut self) -> impl Iterator<Item = (Instance<T>, Instance<View<T>>)> {
+        let this_run = self.ticks.this_run();
+        let (tick, pending) = &mut *self.pending;
+        if *tick != Some(this_run) {
+            *tick = Some(this_run);
+            pending.clear();
+        }
+        let commands = &mut self.commands;
+        self.instances
+            .iter()
+            .map(move |(instance, viewable, save, unload)| {
+                let view = match viewable {
+                    Some(viewable) => viewable.view(),
+                    None => *pending.entry(instance.entity()).or_insert_with(|| {
+                        crate::insert_view(
+                            instance,
+                            unload.map_or(save, |unload| unload.0),
+                            commands,
+                        )
+                    }),
+                };
+                (instance, view)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_app::prelude::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    struct Selected;
+
+    /// Number of new views seen in each update.
+    #[derive(Resource, Default)]
+    struct Seen(Vec<usize>);
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins);
+        app
+    }
+
+    #[test]
+    fn view_query_follows_viewable() {
+        let mut app = app();
+        let node = app.world_mut().spawn(Node).id();
+        let other = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        app.world_mut().entity_mut(view).insert(Health(1));
+
+        app.world_mut()
+            .run_system_once(move |mut views: ViewQuery<Node, &mut Health>| {
+                assert_eq!(views.get(node), Some(&Health(1)));
+                assert!(views.get(other).is_none());
+                assert!(!views.contains(other));
+                views.get_mut(node).unwrap().0 = 2;
+            })
+            .unwrap();
+        assert_eq!(app.world().get::<Health>(view), Some(&Health(2)));
+    }
+
+    #[test]
+    fn new_views_only_during_build_update() {
+        let mut app = app();
+        app.init_resource::<Seen>().add_systems(
+            Update,
+            |views: NewViews<Node>, mut seen: ResMut<Seen>| {
+                seen.0.push(views.iter().count());
+            },
+        );
+        app.world_mut().spawn(Node);
+        app.world_mut().spawn(Node);
+        app.update();
+        app.update();
+        app.world_mut().spawn(Node);
+        app.update();
+        assert_eq!(app.world().resource::<Seen>().0, [2, 0, 1]);
+    }
+
+    #[test]
+    fn views_where_filters_viewables() {
+        let mut app = app();
+        let selected = app.world_mut().spawn((Node, Selected)).id();
+        app.world_mut().spawn(Node);
+        app.update();
+        let view = app.world().view::<Node>(selected).unwrap();
+
+        let views = app
+            .world_mut()
+            .run_system_once(|views: ViewsWhere<Node, With<Selected>>| {
+                views.iter().collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(views, [view]);
+    }
+
+    #[test]
+    fn lazy_view_spawned_on_first_access() {
+        let mut app = app();
+        app.add_view_lazy::<Node>();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        assert!(!app.world().has_view::<Node>(node));
+
+        let views = app
+            .world_mut()
+            .run_system_once(|mut views: LazyView<Node>| views.iter().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(views.len(), 1);
+        let (instance, view) = views[0];
+        assert_eq!(instance.entity(), node);
+        assert_eq!(app.world().view::<Node>(node), Some(view));
+
+        app.update();
+        assert!(app.world().get::<View<Node>>(view.entity()).is_some());
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::component::{ComponentId, Tick};
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_utils::tracing::warn;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// A [`Resource`] which stores the components set using
+/// [`add_view_reflect_mirror`](crate::RegisterView::add_view_reflect_mirror).
+#[derive(Resource)]
+pub struct ViewReflectMirror<T: Kind> {
+    components: Vec<ComponentId>,
+    resolved: Option<Vec<(ComponentId, ReflectComponent)>>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewReflectMirror<T> {
+    pub fn new(components: &[ComponentId]) -> Self {
+        Self {
+            components: components.to_vec(),
+            resolved: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Resolves the [`ReflectComponent`] of each component, skipping those which are not reflectable.
+    fn resolve(&mut self, world: &World) -> Vec<(ComponentId, ReflectComponent)> {
+        if let Some(resolved) = &self.resolved {
+            return resolved.clone();
+        }
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let resolved: Vec<_> = self
+            .components
+            .iter()
+            .filter_map(|&id| {
+                let reflect = world
+                    .components()
+                    .get_info(id)
+                    .and_then(|info| info.type_id())
+                    .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id));
+                if reflect.is_none() {
+                    warn!("{id:?} is not a reflectable component and is not mirrored");
+                }
+                reflect.map(|reflect| (id, reflect.clone()))
+            })
+            .collect();
+        self.resolved = Some(resolved.clone());
+        resolved
+    }
+}
+
+/// Clones the mirrored components of each viewable entity of [`Kind`] `T` into its view whenever they change.
+///
+/// Components are also cloned when the view is first spawned.
+pub fn mirror_reflected_views<T: Kind>(world: &mut World, mut last_run: Local<Tick>) {
+    let this_run = world.change_tick();
+    let components =
+        world.resource_scope(|world, mut mirror: Mut<ViewReflectMirror<T>>| mirror.resolve(world));
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let viewables: Vec<(Entity, Entity)> = world
+        .query::<(Entity, &Viewable<T>)>()
+        .iter(world)
+        .map(|(entity, viewable)| (entity, viewable.view().entity()))
+        .collect();
+    for (entity, view) in viewables {
+        let added = world
+            .entity(entity)
+            .get_change_ticks::<Viewable<T>>()
+            .is_some_and(|ticks| ticks.is_added(*last_run, this_run));
+        for (id, reflect) in &components {
+            let viewable = world.entity(entity);
+            let changed = viewable
+                .get_change_ticks_by_id(*id)
+                .is_some_and(|ticks| ticks.is_changed(*last_run, this_run));
+            if !added && !changed {
+                continue;
+            }
+            let Some(value) = reflect.reflect(viewable).map(|value| value.clone_value()) else {
+                continue;
+            };
+            let Ok(mut view) = world.get_entity_mut(view) else {
+                continue;
+            };
+            reflect.apply_or_insert(&mut view, value.as_partial_reflect(), &registry);
+        }
+    }
+    *last_run = this_run;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_ecs::reflect::ReflectComponent;
+    use bevy_reflect::Reflect;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[test]
+    fn reflected_component_is_mirrored() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins).register_type::<Health>();
+        let health = app.world_mut().register_component::<Health>();
+        app.add_view_reflect_mirror::<Node>(&[health]);
+
+        let node = app.world_mut().spawn((Node, Health(5))).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(app.world().get::<Health>(view).unwrap().0, 5);
+
+        app.world_mut().get_mut::<Health>(node).unwrap().0 = 7;
+        app.update();
+        assert_eq!(app.world().get::<Health>(view).unwrap().0, 7);
+    }
+}
@@ -0,0 +1,154 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemState;
+use bevy_ecs::world::CommandQueue;
+use bevy_hierarchy::prelude::*;
+use bevy_utils::{
+    tracing::{debug, warn},
+    HashMap,
+};
+
+use moonshine_core::prelude::*;
+
+use crate::{BuildView, View, ViewBundle};
+
+/// A [`Resource`] which maps viewable entities of a source [`World`] to their views in a destination [`World`].
+///
+/// This resource must be inserted into the destination world before calling [`build_views_into`].
+/// It may only be used with the source world it was [created](Self::new) for.
+///
+/// # Limitations
+///
+/// Entities are only valid within the world which spawned them. This means [`Viewable`](crate::Viewable)
+/// is never inserted into the source world, and [`View::viewable`] of a remote view refers to an entity in the
+/// source world, not the destination world. Use this resource to associate the two instead.
+#[derive(Resource)]
+pub struct RemoteViews<T: Kind> {
+    views: HashMap<Entity, Instance<View<T>>>,
+    objects: SystemState<Objects<'static, 'static, T>>,
+}
+
+impl<T: Kind> RemoteViews<T> {
+    /// Creates a new empty mapping for viewable entities of the given source [`World`].
+    ///
+    /// Bevy requires exclusive access to a world to initialize its queries, so this is the only point at which
+    /// the source world is borrowed mutably. Afterwards, views are built from a shared reference.
+    pub fn new(src: &mut World) -> Self {
+        Self {
+            views: HashMap::default(),
+            objects: SystemState::new(src),
+        }
+    }
+
+    /// Returns the [`View`] associated with the given viewable [`Entity`] of the source world, if it exists.
+    pub fn view(&self, viewable: Entity) -> Option<Instance<View<T>>> {
+        self.views.get(&viewable).copied()
+    }
+
+    /// Iterates over all viewable entities of the source world and their views.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Instance<View<T>>)> + '_ {
+        self.views.iter().map(|(&entity, &view)| (entity, view))
+    }
+}
+
+/// Builds views in `dst` for all instances of [`Kind`] `T` in `src` and despawns views of those which no longer exist.
+///
+/// This is the building block for bridging a separate view [`World`], such as one owned by a sub-app.
+/// It does not register any systems. Instead, call it from your own synchronization logic whenever both worlds
+/// are accessible. The source world is only read. The mapping between viewables and their views is stored as
+/// [`RemoteViews<T>`] in `dst`, which must be inserted once before the first call. Nothing is built otherwise.
+///
+/// Only views of [`BuildView<T>`] for `T` itself are built. Synchronizing the views after they're built is
+/// left to the user.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // ...
+///     }
+/// }
+///
+/// let mut main = World::new();
+/// let mut render = World::new();
+///
+/// render.insert_resource(RemoteViews::<Bird>::new(&mut main));
+///
+/// let bird = main.spawn(Bird).id();
+/// moonshine_view::build_views_into::<Bird>(&main, &mut render);
+///
+/// let views = render.resource::<RemoteViews<Bird>>();
+/// assert!(views.view(bird).is_some());
+/// ```
+pub fn build_views_into<T: BuildView>(src: &World, dst: &mut World) {
+    let Some(mut remote) = dst.remove_resource::<RemoteViews<T>>() else {
+        warn!(
+            "RemoteViews<{}> must be inserted before building views into another world",
+            T::debug_name()
+        );
+        return;
+    };
+    let objects = remote.objects.get(src);
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, dst);
+
+    remote.views.retain(|&entity, view| {
+        if objects.contains(entity) {
+            return true;
+        }
+        if let Some(view_entity) = commands.get_entity(view.entity()) {
+            view_entity.despawn_recursive();
+        }
+        debug!("{view:?} despawned for remote {entity:?}");
+        false
+    });
+
+    for object in objects.iter() {
+        let entity = object.entity();
+        if remote.views.contains_key(&entity) {
+            continue;
+        }
+        let view = commands.spawn_instance(ViewBundle::new(object)).instance();
+        remote.views.insert(entity, view);
+        T::build(src, object, commands.instance(view));
+        debug!("{view:?} spawned for remote {entity:?}");
+    }
+
+    queue.apply(dst);
+    dst.insert_resource(remote);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::Node;
+
+    use super::*;
+
+    #[test]
+    fn remote_views_follow_source_world() {
+        let mut src = World::new();
+        let mut dst = World::new();
+        dst.insert_resource(RemoteViews::<Node>::new(&mut src));
+
+        let a = src.spawn(Node).id();
+        let b = src.spawn(Node).id();
+        build_views_into::<Node>(&src, &mut dst);
+        let view_a = dst.resource::<RemoteViews<Node>>().view(a).unwrap();
+        let view_b = dst.resource::<RemoteViews<Node>>().view(b).unwrap();
+        assert!(dst.get::<View<Node>>(view_a.entity()).is_some());
+
+        src.despawn(a);
+        build_views_into::<Node>(&src, &mut dst);
+        let remote = dst.resource::<RemoteViews<Node>>();
+        assert!(remote.view(a).is_none());
+        assert_eq!(remote.view(b), Some(view_b));
+        assert!(dst.get_entity(view_a.entity()).is_err());
+        assert_eq!(remote.iter().count(), 1);
+    }
+}
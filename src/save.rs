@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::debug;
+
+use moonshine_core::prelude::*;
+
+use crate::{Retargeted, View, Viewable, Viewables};
+
+/// Marks each new view of [`Kind`] `T` with [`Save`] instead of [`Unload`].
+pub fn insert_view_save<T: Kind>(
+    query: Query<&Viewable<T>, Added<Viewable<T>>>,
+    mut commands: Commands,
+) {
+    for viewable in query.iter() {
+        commands
+            .entity(viewable.view().entity())
+            .remove::<Unload>()
+            .insert(Save);
+    }
+}
+
+/// Associates each loaded view of [`Kind`] `T` with its loaded viewable entity, so that it is not built again.
+#[allow(clippy::type_complexity)]
+pub fn restore_saved_views<T: Kind>(
+    views: Query<(Instance<View<T>>, &View<T>), Added<View<T>>>,
+    viewables: Query<&Viewable<T>>,
+    mut registry: ResMut<Viewables>,
+    mut commands: Commands,
+) {
+    for (view, data) in views.iter() {
+        if registry.contains_view(view.entity()) {
+            continue;
+        }
+        let viewable = data.viewable().entity();
+        if viewables
+            .get(viewable)
+            .is_ok_and(|viewable| viewable.view() == view)
+        {
+            registry.add(viewable, view);
+            commands
+                .entity(viewable)
+                .insert(Retargeted::<T>(PhantomData));
+            debug!("{view:?} restored for {viewable:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_app::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use moonshine_core::load::{load, LoadPlugin};
+    use moonshine_core::save::{save_default, SavePlugin};
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Bird;
+
+    /// Inserted into each view of [`Bird`] when it is built. Not saved.
+    #[derive(Component)]
+    struct Built;
+
+    impl BuildView for Bird {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.insert(Built);
+        }
+    }
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Bird>();
+        app.add_plugins((MinimalPlugins, SavePlugin, LoadPlugin))
+            .register_type::<Bird>()
+            .add_view_saved::<Bird>();
+        app
+    }
+
+    #[test]
+    fn saved_view_is_restored_without_rebuild() {
+        let path = std::env::temp_dir().join("moonshine_view_saved_view_is_restored.ron");
+
+        let mut saved = app();
+        saved.world_mut().spawn((Bird, Save));
+        saved.update();
+        assert_eq!(saved.world().view_count::<Bird>(), 1);
+        saved.add_systems(PreUpdate, save_default().into(static_file(&path)));
+        saved.update();
+
+        let mut app = app();
+        app.add_systems(PreUpdate, load(static_file(&path)));
+        app.update();
+        std::fs::remove_file(&path).unwrap();
+
+        let world = app.world_mut();
+        let (bird, viewable) = world.query::<(Entity, &Viewable<Bird>)>().single(world);
+        let view = viewable.view();
+        assert_eq!(
+            world
+                .get::<View<Bird>>(view.entity())
+                .unwrap()
+                .viewable()
+                .entity(),
+            bird
+        );
+        assert!(world.get::<Built>(view.entity()).is_none());
+        assert_eq!(world.view_count::<Bird>(), 1);
+        let views: Vec<_> = world.resource::<Viewables>().views(bird).collect();
+        assert_eq!(views, [view.entity()]);
+    }
+
+    #[test]
+    fn loaded_viewable_gets_one_view() {
+        let path = std::env::temp_dir().join("moonshine_view_loaded_viewable_gets_one_view.ron");
+        let app = || {
+            let mut app = ViewTestApp::with_viewable::<Bird>();
+            app.add_plugins((MinimalPlugins, SavePlugin, LoadPlugin))
+                .register_type::<Bird>();
+            app
+        };
+
+        let mut saved = app();
+        saved.world_mut().spawn((Bird, Save));
+        saved.update();
+        saved.add_systems(PreUpdate, save_default().into(static_file(&path)));
+        saved.update();
+
+        let mut app = app();
+        app.add_systems(PreUpdate, load(static_file(&path)));
+        app.update();
+        std::fs::remove_file(&path).unwrap();
+
+        let world = app.world_mut();
+        assert_eq!(world.view_count::<Bird>(), 1);
+        let (bird, viewable) = world.query::<(Entity, &Viewable<Bird>)>().single(world);
+        let view = viewable.view().entity();
+        assert_eq!(
+            world.get::<View<Bird>>(view).unwrap().viewable().entity(),
+            bird
+        );
+        assert!(world.get::<Built>(view).is_some());
+    }
+}
@@ -0,0 +1,84 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+/// A [`Component`] which marks a viewable entity of [`Kind`] `T` as settled.
+///
+/// See [`add_view_settled`](crate::RegisterView::add_view_settled) for details.
+#[derive(Component)]
+pub struct ViewSettled<T: Kind>(PhantomData<T>);
+
+/// Returns `true` if the given viewable instance has not persisted for a full update yet.
+pub fn is_unsettled<T: Kind>(world: &World, instance: Instance<T>) -> bool {
+    world.get::<ViewSettled<T>>(instance.entity()).is_none()
+}
+
+/// Marks every viewable entity of [`Kind`] `T` as [`ViewSettled<T>`] at the end of the update.
+pub fn settle_views<T: Kind>(
+    entities: Query<Entity, (T::Filter, Without<ViewSettled<T>>)>,
+    mut commands: Commands,
+) {
+    for entity in entities.iter() {
+        commands
+            .entity(entity)
+            .insert(ViewSettled::<T>(PhantomData));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_app::prelude::*;
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Crate;
+
+    #[derive(Component)]
+    struct Size(u32);
+
+    /// The [`Size`] seen by each [`Crate`] view when it is built.
+    #[derive(Resource, Default)]
+    struct Built(Vec<Option<u32>>);
+
+    impl BuildView for Crate {
+        fn build(world: &World, object: Object<Self>, mut view: ViewCommands<Self>) {
+            let size = world.get::<Size>(object.entity()).map(|size| size.0);
+            view.commands()
+                .queue(move |world: &mut World| world.resource_mut::<Built>().0.push(size));
+        }
+    }
+
+    #[test]
+    fn view_built_from_completed_viewable() {
+        let mut app = ViewTestApp::with_viewable::<Crate>();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Built>()
+            .add_view_settled::<Crate>()
+            .add_systems(First, |mut commands: Commands, mut spawned: Local<bool>| {
+                if !*spawned {
+                    commands.spawn(Crate);
+                    *spawned = true;
+                }
+            })
+            .add_systems(
+                Update,
+                |query: Query<Entity, (With<Crate>, Without<Size>)>, mut commands: Commands| {
+                    for entity in query.iter() {
+                        commands.entity(entity).insert(Size(3));
+                    }
+                },
+            );
+        app.update();
+        assert!(app.world().resource::<Built>().0.is_empty());
+
+        app.update();
+        assert_eq!(app.world().resource::<Built>().0, [Some(3)]);
+    }
+}
@@ -0,0 +1,294 @@
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::{tracing::debug, HashMap};
+
+use moonshine_core::prelude::*;
+
+use crate::{despawn_view_recursive, IsView};
+
+/// A [`Component`] which represents a view shared between multiple viewable entities of [`Kind`] `T`.
+///
+/// Shared views are registered using [`add_shared_viewable`](crate::RegisterView::add_shared_viewable).
+/// Unlike [`View<T>`](crate::View), a shared view has no single viewable entity. Instead, it tracks all of its
+/// members, each of which has a [`SharedViewable<T>`] which refers back to it.
+///
+/// Shared views are not built by [`BuildView`](crate::BuildView). Query for `Added<SharedView<T>>` to build them,
+/// and `Changed<SharedView<T>>` to update them whenever their members change.
+#[derive(Component)]
+pub struct SharedView<T: Kind> {
+    members: Vec<Entity>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> SharedView<T> {
+    /// Iterates over all viewable entities which share this view.
+    ///
+    /// Members which are despawned or no longer of kind `T` are only removed once per update, so any member
+    /// may be invalid until then. Use [`Objects<T>`] or [`Query::get`] to access them safely.
+    pub fn members(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.members.iter().copied()
+    }
+
+    /// Returns the number of viewable instances which share this view.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if this view has no members.
+    ///
+    /// Empty shared views are despawned, so this is only ever `true` briefly.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns `true` if the given viewable entity shares this view.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.members.contains(&entity)
+    }
+}
+
+/// A [`Component`] which refers to the [`SharedView`] of a viewable entity of [`Kind`] `T`.
+#[derive(Component)]
+pub struct SharedViewable<T: Kind> {
+    view: Instance<SharedView<T>>,
+}
+
+impl<T: Kind> SharedViewable<T> {
+    /// Returns the [`SharedView`] of this viewable entity.
+    pub fn view(&self) -> Instance<SharedView<T>> {
+        self.view
+    }
+}
+
+type SharedViewKey<T, K> = Box<dyn Fn(&World, Instance<T>) -> K + Send + Sync>;
+
+/// A [`Resource`] which stores the key function set using [`add_shared_viewable`](crate::RegisterView::add_shared_viewable),
+/// and the shared view of each key.
+#[derive(Resource)]
+pub struct SharedViews<T: Kind, K> {
+    key: SharedViewKey<T, K>,
+    views: HashMap<K, Instance<SharedView<T>>>,
+}
+
+impl<T: Kind, K: Eq + Hash> SharedViews<T, K> {
+    pub fn new(key: impl Fn(&World, Instance<T>) -> K + Send + Sync + 'static) -> Self {
+        Self {
+            key: Box::new(key),
+            views: HashMap::default(),
+        }
+    }
+}
+
+/// Assigns every viewable instance of [`Kind`] `T` to the [`SharedView`] of its key.
+///
+/// New shared views are spawned for new keys. Instances whose key has changed are moved to the shared view of
+/// their new key, and shared views which are left without any members are despawned. If a shared view is
+/// despawned externally, a new one is spawned for its members.
+pub fn assign_shared_views<T: Kind, K: Eq + Hash + Send + Sync + 'static>(world: &mut World) {
+    world.resource_scope(|world, mut shared: Mut<SharedViews<T, K>>| {
+        shared.views.retain(|_, view| {
+            let exists = world.get::<SharedView<T>>(view.entity()).is_some();
+            if !exists {
+                debug!(
+                    "{view:?} was despawned externally as a shared view of {}",
+                    T::debug_name()
+                );
+            }
+            exists
+        });
+
+        let mut query = world.query_filtered::<(Entity, Option<&SharedViewable<T>>), T::Filter>();
+        let assignments: Vec<_> = query
+            .iter(world)
+            .filter_map(|(entity, viewable)| {
+                // SAFE: Entity matches `T::Filter`.
+                let instance = unsafe { Instance::<T>::from_entity_unchecked(entity) };
+                let key = (shared.key)(world, instance);
+                let current = viewable.map(SharedViewable::view);
+                match shared.views.get(&key) {
+                    Some(&view) if current == Some(view) => None,
+                    _ => Some((entity, key, current)),
+                }
+            })
+            .collect();
+
+        for (entity, key, current) in assignments {
+            if let Some(current) = current {
+                leave_shared_view(world, &mut shared, entity, current);
+            }
+            let view = *shared.views.entry(key).or_insert_with(|| {
+                let view = world
+                    .spawn((
+                        SharedView::<T> {
+                            members: Vec::new(),
+                            marker: PhantomData,
+                        },
+                        IsView,
+                    ))
+                    .id();
+                debug!("{view:?} spawned as a shared view of {}", T::debug_name());
+                // SAFE: `SharedView<T>` was just inserted.
+                unsafe { Instance::from_entity_unchecked(view) }
+            });
+            world
+                .get_mut::<SharedView<T>>(view.entity())
+                .unwrap()
+                .members
+                .push(entity);
+            world.entity_mut(entity).insert(SharedViewable { view });
+        }
+    });
+}
+
+/// Removes viewable entities which have been despawned or are no longer of [`Kind`] `T` from their [`SharedView`],
+/// and despawns shared views which are left without any members.
+pub fn retain_shared_views<T: Kind, K: Eq + Hash + Send + Sync + 'static>(world: &mut World) {
+    world.resource_scope(|world, mut shared: Mut<SharedViews<T, K>>| {
+        let mut kind = world.query_filtered::<(), T::Filter>();
+        let mut views = world.query::<(Entity, &SharedView<T>)>();
+        let mut departed = Vec::new();
+        for (view, shared_view) in views.iter(world) {
+            for &member in &shared_view.members {
+                if kind.get(world, member).is_err() {
+                    departed.push((member, view));
+                }
+            }
+        }
+        for (member, view) in departed {
+            if let Ok(mut entity) = world.get_entity_mut(member) {
+                entity.remove::<SharedViewable<T>>();
+            }
+            // SAFE: Entity was just queried with `SharedView<T>`.
+            let view = unsafe { Instance::from_entity_unchecked(view) };
+            leave_shared_view(world, &mut shared, member, view);
+        }
+    });
+}
+
+fn leave_shared_view<T: Kind, K: Eq + Hash>(
+    world: &mut World,
+    shared: &mut SharedViews<T, K>,
+    entity: Entity,
+    view: Instance<SharedView<T>>,
+) {
+    let Some(mut shared_view) = world.get_mut::<SharedView<T>>(view.entity()) else {
+        return;
+    };
+    shared_view.members.retain(|&member| member != entity);
+    if shared_view.is_empty() {
+        shared.views.retain(|_, &mut other| other != view);
+        despawn_view_recursive(world, view.entity());
+        debug!("{view:?} despawned as a shared view of {}", T::debug_name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Tree;
+
+    #[derive(Component)]
+    struct Cell(i32);
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::new();
+        app.add_plugins(MinimalPlugins)
+            .add_shared_viewable::<Tree, i32>(|world, tree| {
+                world.get::<Cell>(tree.entity()).unwrap().0
+            });
+        app
+    }
+
+    fn shared_view_of(app: &ViewTestApp, entity: Entity) -> Option<Entity> {
+        app.world()
+            .get::<SharedViewable<Tree>>(entity)
+            .map(|viewable| viewable.view().entity())
+    }
+
+    fn member_count(app: &ViewTestApp, view: Entity) -> usize {
+        app.world().get::<SharedView<Tree>>(view).unwrap().len()
+    }
+
+    #[test]
+    fn shared_view_torn_down_with_last_member() {
+        let mut app = app();
+        let trees: Vec<Entity> = (0..3)
+            .map(|_| app.world_mut().spawn((Tree, Cell(0))).id())
+            .collect();
+        app.update();
+
+        let view = shared_view_of(&app, trees[0]).unwrap();
+        assert!(trees
+            .iter()
+            .all(|&tree| shared_view_of(&app, tree) == Some(view)));
+        assert_eq!(member_count(&app, view), 3);
+        assert!(app.world().is_view(view));
+
+        app.world_mut().despawn(trees[0]);
+        app.update();
+        assert_eq!(member_count(&app, view), 2);
+
+        app.world_mut().despawn(trees[1]);
+        app.update();
+        assert_eq!(member_count(&app, view), 1);
+
+        app.world_mut().entity_mut(trees[2]).remove::<Tree>();
+        app.update();
+        assert!(app.world().get_entity(view).is_err());
+        assert_eq!(shared_view_of(&app, trees[2]), None);
+    }
+
+    #[test]
+    fn member_moves_to_shared_view_of_new_key() {
+        let mut app = app();
+        let a = app.world_mut().spawn((Tree, Cell(0))).id();
+        let b = app.world_mut().spawn((Tree, Cell(0))).id();
+        app.update();
+        let view = shared_view_of(&app, a).unwrap();
+
+        app.world_mut().get_mut::<Cell>(b).unwrap().0 = 1;
+        app.update();
+        let other = shared_view_of(&app, b).unwrap();
+        assert!(other != view);
+        assert_eq!(member_count(&app, view), 1);
+
+        app.world_mut().get_mut::<Cell>(b).unwrap().0 = 0;
+        app.update();
+        assert!(app.world().get_entity(other).is_err());
+        assert_eq!(shared_view_of(&app, b), Some(view));
+        assert_eq!(member_count(&app, view), 2);
+    }
+
+    #[test]
+    fn shared_view_respawned_after_external_despawn() {
+        let mut app = app();
+        let trees: Vec<Entity> = (0..2)
+            .map(|_| app.world_mut().spawn((Tree, Cell(0))).id())
+            .collect();
+        app.update();
+        let view = shared_view_of(&app, trees[0]).unwrap();
+
+        app.world_mut().despawn(view);
+        app.update();
+        let respawned = shared_view_of(&app, trees[0]).unwrap();
+        assert!(respawned != view);
+        assert_eq!(shared_view_of(&app, trees[1]), Some(respawned));
+        assert_eq!(member_count(&app, respawned), 2);
+        let members: Vec<_> = app
+            .world()
+            .get::<SharedView<Tree>>(respawned)
+            .unwrap()
+            .members()
+            .collect();
+        assert_eq!(members, trees);
+    }
+}
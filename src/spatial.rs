@@ -0,0 +1,76 @@
+use bevy_ecs::prelude::*;
+use bevy_render::prelude::*;
+use bevy_transform::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::Viewable;
+
+/// Inserts [`Transform`] and [`Visibility`] into each new view of [`Kind`] `T`, unless it already has them.
+///
+/// Any other spatial components, such as [`GlobalTransform`] and [`InheritedVisibility`], are required by these.
+pub fn insert_view_spatial<T: Kind>(
+    query: Query<&Viewable<T>, Added<Viewable<T>>>,
+    mut commands: Commands,
+) {
+    for viewable in query.iter() {
+        commands
+            .entity(viewable.view().entity())
+            .insert_if_new((Transform::default(), Visibility::default()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Hidden;
+
+    impl BuildView for Hidden {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.insert((Transform::from_xyz(1.0, 0.0, 0.0), Visibility::Hidden));
+        }
+    }
+
+    #[test]
+    fn spatial_components_are_inserted() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins).add_view_spatial::<Node>();
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(
+            app.world().get::<Transform>(view),
+            Some(&Transform::default())
+        );
+        assert_eq!(
+            app.world().get::<Visibility>(view),
+            Some(&Visibility::Inherited)
+        );
+        assert!(app.world().get::<GlobalTransform>(view).is_some());
+        assert!(app.world().get::<InheritedVisibility>(view).is_some());
+    }
+
+    #[test]
+    fn built_spatial_components_are_preserved() {
+        let mut app = ViewTestApp::with_viewable::<Hidden>();
+        app.add_plugins(MinimalPlugins).add_view_spatial::<Hidden>();
+        let hidden = app.world_mut().spawn(Hidden).id();
+        app.update();
+        let view = app.world().view::<Hidden>(hidden).unwrap().entity();
+        assert_eq!(
+            app.world().get::<Transform>(view),
+            Some(&Transform::from_xyz(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            app.world().get::<Visibility>(view),
+            Some(&Visibility::Hidden)
+        );
+    }
+}
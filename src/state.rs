@@ -0,0 +1,73 @@
+use bevy_ecs::prelude::*;
+use bevy_state::prelude::*;
+use bevy_utils::tracing::debug;
+
+use moonshine_core::prelude::*;
+
+use crate::{despawn_view_recursive, Viewable};
+
+/// Returns `true` if the current [`State`] of `S` is not `state`, or if `S` is not initialized.
+pub fn is_not_in_state<S: States>(world: &World, state: &S) -> bool {
+    world
+        .get_resource::<State<S>>()
+        .is_none_or(|current| current.get() != state)
+}
+
+/// Despawns all views of [`Kind`] `T` immediately, and keeps their viewable entities.
+pub fn despawn_views<T: Kind>(world: &mut World) {
+    let views: Vec<Entity> = world
+        .query::<&Viewable<T>>()
+        .iter(world)
+        .map(|viewable| viewable.view().entity())
+        .collect();
+    for view in views {
+        despawn_view_recursive(world, view);
+        debug!("{view:?} despawned on state exit");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+    use bevy_state::app::StatesPlugin;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(States, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+    enum Mode {
+        #[default]
+        Menu,
+        Game,
+    }
+
+    fn set_mode(app: &mut ViewTestApp, mode: Mode) {
+        app.world_mut().resource_mut::<NextState<Mode>>().set(mode);
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn views_follow_state() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<Mode>()
+            .add_view_in_state::<Node, _>(Mode::Game);
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        assert!(!app.world().has_view::<Node>(node));
+
+        set_mode(&mut app, Mode::Game);
+        let view = app.world().view::<Node>(node).unwrap().entity();
+
+        set_mode(&mut app, Mode::Menu);
+        assert!(app.world().get_entity(view).is_err());
+        assert!(!app.world().has_view::<Node>(node));
+        assert!(app.world().get::<Node>(node).is_some());
+
+        set_mode(&mut app, Mode::Game);
+        assert!(app.world().has_view::<Node>(node));
+    }
+}
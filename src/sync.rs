@@ -0,0 +1,163 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemId;
+
+use moonshine_core::prelude::*;
+
+use crate::{View, Viewable};
+
+type OnChange<T, S> = Box<dyn Fn(&S, Instance<View<T>>, &mut Commands) + Send + Sync>;
+
+/// A [`Resource`] which stores the callback added using
+/// [`add_view_state_sync`](crate::RegisterView::add_view_state_sync).
+#[derive(Resource)]
+pub struct ViewStateSync<T: Kind, S: Component> {
+    on_change: OnChange<T, S>,
+}
+
+impl<T: Kind, S: Component> ViewStateSync<T, S> {
+    pub fn new(
+        on_change: impl Fn(&S, Instance<View<T>>, &mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_change: Box::new(on_change),
+        }
+    }
+}
+
+/// A [`Component`] which stores the last state `S` synchronized to a view.
+#[derive(Component)]
+pub struct SyncedState<T: Kind, S: Component> {
+    state: S,
+    marker: PhantomData<T>,
+}
+
+/// Invokes the [`ViewStateSync<T, S>`] callback for each viewable of [`Kind`] `T` whose state `S` differs from the
+/// state last synchronized to its view, including new views.
+#[allow(clippy::type_complexity)]
+pub fn sync_view_state<T: Kind, S: Component + PartialEq + Clone>(
+    viewables: Query<(&S, &Viewable<T>), Or<(Changed<S>, Changed<Viewable<T>>)>>,
+    synced: Query<Option<&SyncedState<T, S>>, With<View<T>>>,
+    sync: Res<ViewStateSync<T, S>>,
+    mut commands: Commands,
+) {
+    for (state, viewable) in viewables.iter() {
+        let view = viewable.view();
+        let Ok(last) = synced.get(view.entity()) else {
+            continue;
+        };
+        if last.is_some_and(|last| last.state == *state) {
+            continue;
+        }
+        (sync.on_change)(state, view, &mut commands);
+        commands.entity(view.entity()).insert(SyncedState::<T, S> {
+            state: state.clone(),
+            marker: PhantomData,
+        });
+    }
+}
+
+/// A [`Resource`] which stores one-shot copies of the systems which synchronize views of [`Kind`] `T`.
+///
+/// See [`sync_views_now`](crate::ViewWorld::sync_views_now) for details.
+#[derive(Resource)]
+pub struct ViewSyncSystems<T: Kind> {
+    systems: Vec<SystemId>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> Default for ViewSyncSystems<T> {
+    fn default() -> Self {
+        Self {
+            systems: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Kind> ViewSyncSystems<T> {
+    pub fn systems(&self) -> &[SystemId] {
+        &self.systems
+    }
+}
+
+/// Registers a one-shot copy of the given system to be run by [`sync_views_now`](crate::ViewWorld::sync_views_now).
+pub fn register_sync_system<T: Kind, M>(
+    world: &mut World,
+    system: impl IntoSystem<(), (), M> + 'static,
+) {
+    let system = world.register_system(system);
+    world
+        .get_resource_or_insert_with(ViewSyncSystems::<T>::default)
+        .systems
+        .push(system);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Component, PartialEq, Clone)]
+    enum Pose {
+        Idle,
+        Walk,
+    }
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Animation(&'static str);
+
+    fn animate(pose: &Pose, view: Instance<View<Node>>, commands: &mut Commands) {
+        let animation = match pose {
+            Pose::Idle => Animation("idle"),
+            Pose::Walk => Animation("walk"),
+        };
+        commands.entity(view.entity()).insert(animation);
+    }
+
+    #[test]
+    fn duplicate_state_sync_is_ignored() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_state_sync::<Node, Pose>(animate)
+            .add_view_state_sync::<Node, Pose>(|_, view, commands| {
+                commands.entity(view.entity()).insert(Animation("none"));
+            });
+
+        let node = app.world_mut().spawn((Node, Pose::Idle)).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(app.world().get::<Animation>(view), Some(&Animation("idle")));
+
+        *app.world_mut().get_mut::<Pose>(node).unwrap() = Pose::Walk;
+        app.update();
+        assert_eq!(app.world().get::<Animation>(view), Some(&Animation("walk")));
+    }
+
+    #[test]
+    fn state_synced_without_update() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_state_sync::<Node, Pose>(animate);
+        let node = app.world_mut().spawn((Node, Pose::Idle)).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+
+        // Simulate a load which changes the state of existing viewables.
+        *app.world_mut().get_mut::<Pose>(node).unwrap() = Pose::Walk;
+        app.world_mut().sync_views_now::<Node>();
+        assert_eq!(app.world().get::<Animation>(view), Some(&Animation("walk")));
+
+        *app.world_mut().get_mut::<Pose>(node).unwrap() = Pose::Idle;
+        app.world_mut().sync_views_now::<Node>();
+        assert_eq!(app.world().get::<Animation>(view), Some(&Animation("idle")));
+
+        app.update();
+        assert_eq!(app.world().get::<Animation>(view), Some(&Animation("idle")));
+    }
+}
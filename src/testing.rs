@@ -0,0 +1,193 @@
+//! Utilities for testing views with precise control over when view systems run.
+//!
+//! # Example
+//! ```
+//! # use bevy::prelude::*;
+//! # use moonshine_core::prelude::*;
+//! # use moonshine_view::prelude::*;
+//! use moonshine_view::testing::ViewTestApp;
+//!
+//! #[derive(Component)]
+//! struct Bird;
+//!
+//! impl BuildView for Bird {
+//!     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+//!         // ...
+//!     }
+//! }
+//!
+//! let mut app = ViewTestApp::new();
+//! app.add_viewable::<Bird>();
+//!
+//! let bird = app.world_mut().spawn(Bird).id();
+//! app.step_build::<Bird>();
+//! let view = app.world().get::<Viewable<Bird>>(bird).unwrap().view();
+//!
+//! app.world_mut().entity_mut(bird).remove::<Bird>();
+//! app.step_despawn::<Bird>();
+//! assert!(app.world().get_entity(view.entity()).is_err());
+//! ```
+
+use std::ops::{Deref, DerefMut};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
+
+use moonshine_core::prelude::*;
+
+#[cfg(test)]
+use crate::ViewCommands;
+use crate::{build_view, despawn_view, spawn_view, BuildView, RegisterView};
+
+/// A wrapper around an [`App`] which runs view systems on demand.
+///
+/// Each step runs only the relevant systems once, in isolation, and applies their commands immediately.
+/// This allows tests to interleave spawning, despawning and rebuilding views without depending on the
+/// scheduling of a full [`App::update`]. Any other features registered for a kind, such as debouncing or
+/// transitions, are only run by [`step_all`](Self::step_all).
+pub struct ViewTestApp {
+    app: App,
+}
+
+impl ViewTestApp {
+    /// Creates a new empty [`ViewTestApp`].
+    pub fn new() -> Self {
+        Self { app: App::new() }
+    }
+
+    /// Creates a new [`ViewTestApp`] with [`Kind`] `T` registered as viewable.
+    ///
+    /// See [`add_viewable`](crate::RegisterView::add_viewable) for details.
+    pub fn with_viewable<T: BuildView>() -> Self {
+        let mut app = Self::new();
+        app.add_viewable::<T>();
+        app
+    }
+
+    /// Spawns and builds views for all viewable instances of [`Kind`] `T` without a view.
+    ///
+    /// Only the [`BuildView<T>`] implementation of `T` itself is run.
+    /// Use [`step_build_view`](Self::step_build_view) to run any other implementation.
+    pub fn step_build<T: BuildView>(&mut self) -> &mut Self {
+        self.step_spawn::<T>().step_build_view::<T, T>()
+    }
+
+    /// Spawns views for all viewable instances of [`Kind`] `T` without a view, without building them.
+    pub fn step_spawn<T: Kind>(&mut self) -> &mut Self {
+        self.run(spawn_view::<T>)
+    }
+
+    /// Builds all new views of [`Kind`] `T` using the [`BuildView<T>`] implementation of `V`.
+    pub fn step_build_view<T: Kind, V: BuildView<T>>(&mut self) -> &mut Self {
+        self.run(build_view::<T, V>)
+    }
+
+    /// Despawns all views of [`Kind`] `T` whose viewable entity is despawned or no longer of kind `T`.
+    pub fn step_despawn<T: Kind>(&mut self) -> &mut Self {
+        self.run(despawn_view::<T>)
+    }
+
+    /// Runs all view systems of all viewable kinds once, without running [`Update`].
+    ///
+    /// This runs the [`PreUpdate`], [`PostUpdate`] and [`Last`] schedules in order, skipping any which do not exist.
+    pub fn step_all(&mut self) -> &mut Self {
+        let world = self.app.world_mut();
+        let _ = world.try_run_schedule(PreUpdate);
+        let _ = world.try_run_schedule(PostUpdate);
+        let _ = world.try_run_schedule(Last);
+        self
+    }
+
+    fn run<M>(&mut self, system: impl IntoSystem<(), (), M>) -> &mut Self {
+        self.app.world_mut().run_system_once(system).unwrap();
+        self.app.world_mut().flush();
+        self
+    }
+}
+
+impl Default for ViewTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<App> for ViewTestApp {
+    fn from(app: App) -> Self {
+        Self { app }
+    }
+}
+
+impl Deref for ViewTestApp {
+    type Target = App;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl DerefMut for ViewTestApp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+/// A viewable [`Kind`] with an empty view, used by tests which only care about the lifecycle of views.
+#[cfg(test)]
+#[derive(Component)]
+pub(crate) struct Node;
+
+#[cfg(test)]
+impl BuildView for Node {
+    fn build(_: &World, _: Object<Self>, _: ViewCommands<Self>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Lamp;
+
+    #[derive(Component)]
+    struct Lit;
+
+    impl BuildView for Lamp {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.insert(Lit);
+        }
+    }
+
+    #[test]
+    fn spawn_build_and_despawn_in_steps() {
+        let mut app = ViewTestApp::with_viewable::<Lamp>();
+        let lamp = app.world_mut().spawn(Lamp).id();
+
+        app.step_spawn::<Lamp>();
+        let view = app.world().view::<Lamp>(lamp).unwrap().entity();
+        assert!(app.world().get::<Lit>(view).is_none());
+
+        app.step_build_view::<Lamp, Lamp>();
+        assert!(app.world().get::<Lit>(view).is_some());
+
+        app.world_mut().entity_mut(lamp).remove::<Lamp>();
+        assert!(app.world().get_entity(view).is_ok());
+        app.step_despawn::<Lamp>();
+        assert!(app.world().get_entity(view).is_err());
+        assert!(!app.world().has_view::<Lamp>(lamp));
+    }
+
+    #[test]
+    fn step_all_runs_debounce() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_view_debounce::<Node>(2);
+        let node = app.world_mut().spawn(Node).id();
+
+        app.step_all();
+        assert!(!app.world().has_view::<Node>(node));
+        app.step_all();
+        assert!(app.world().has_view::<Node>(node));
+    }
+}
@@ -0,0 +1,343 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::{ROQueryItem, ReadOnlyQueryData};
+use bevy_time::prelude::*;
+use bevy_transform::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{View, Viewable};
+
+/// Trait used to compute the initial [`Transform`] of a [`View`](crate::View) from its viewable entity.
+///
+/// Remember to register this type using [`RegisterView::add_view_transform`](crate::RegisterView::add_view_transform).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_core::prelude::*;
+/// # use moonshine_view::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Position(Vec3);
+///
+/// #[derive(Component)]
+/// struct Rotation(Quat);
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// impl BuildView for Bird {
+///     fn build(world: &World, object: Object<Self>, view: ViewCommands<Self>) {
+///         // View transform is already inserted!
+///     }
+/// }
+///
+/// impl ViewTransform for Bird {
+///     type Data = (&'static Position, &'static Rotation);
+///
+///     fn view_transform((position, rotation): (&Position, &Rotation)) -> Transform {
+///         Transform::from_translation(position.0).with_rotation(rotation.0)
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_viewable::<Bird>().add_view_transform::<Bird>();
+/// ```
+pub trait ViewTransform: Kind {
+    /// Data queried from the viewable entity to compute the view transform.
+    type Data: ReadOnlyQueryData;
+
+    /// Returns the initial [`Transform`] of the view.
+    ///
+    /// By default, this is [`Transform::default()`].
+    fn view_transform(_data: ROQueryItem<Self::Data>) -> Transform {
+        Transform::default()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn insert_view_transform<T: ViewTransform>(
+    query: Query<(&Viewable<T>, T::Data), Added<Viewable<T>>>,
+    mut commands: Commands,
+) {
+    for (viewable, data) in query.iter() {
+        let transform = T::view_transform(data);
+        commands.entity(viewable.view().entity()).insert(transform);
+    }
+}
+
+impl<T: Kind> Viewable<T> {
+    /// Returns the [`GlobalTransform`] of the [`View`](crate::View) associated with this [`Viewable`].
+    ///
+    /// Returns `None` if the view is despawned or has no [`GlobalTransform`].
+    /// Within systems, prefer to use [`ViewQuery<T, &GlobalTransform>`](crate::ViewQuery) instead.
+    ///
+    /// Note that [`GlobalTransform`] is only updated during [`PostUpdate`](bevy_app::PostUpdate).
+    pub fn view_global_transform(&self, world: &World) -> Option<GlobalTransform> {
+        world.get::<GlobalTransform>(self.view().entity()).copied()
+    }
+}
+
+/// A [`Resource`] which stores the offset set using
+/// [`add_view_offset_transform`](crate::RegisterView::add_view_offset_transform).
+#[derive(Resource)]
+pub struct ViewOffset<T: Kind> {
+    offset: Transform,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewOffset<T> {
+    pub fn new(offset: Transform) -> Self {
+        Self {
+            offset,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Sets the [`Transform`] of each view of [`Kind`] `T` to the transform of its viewable, composed with the [`ViewOffset<T>`].
+///
+/// Viewables without a [`Transform`] are skipped.
+#[allow(clippy::type_complexity)]
+pub fn sync_view_offset_transform<T: Kind>(
+    viewables: Query<(&Transform, &Viewable<T>), Or<(Changed<Transform>, Added<Viewable<T>>)>>,
+    mut views: Query<Option<&mut Transform>, (With<View<T>>, Without<Viewable<T>>)>,
+    offset: Res<ViewOffset<T>>,
+    mut commands: Commands,
+) {
+    for (transform, viewable) in viewables.iter() {
+        let view = viewable.view().entity();
+        let Ok(view_transform) = views.get_mut(view) else {
+            continue;
+        };
+        let target = transform.mul_transform(offset.offset);
+        match view_transform {
+            Some(mut view_transform) => {
+                view_transform.set_if_neq(target);
+            }
+            None => {
+                commands.entity(view).insert(target);
+            }
+        }
+    }
+}
+
+/// A [`Resource`] which stores the snap distance set using
+/// [`add_view_interpolated_transform`](crate::RegisterView::add_view_interpolated_transform).
+#[derive(Resource)]
+pub struct ViewInterpolation<T: Kind> {
+    snap: f32,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewInterpolation<T> {
+    pub fn new(snap: f32) -> Self {
+        Self {
+            snap,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// A [`Component`] which stores the viewable [`Transform`] of the last two fixed updates on a view.
+#[derive(Component, Clone, Copy)]
+pub struct ViewTransformHistory {
+    previous: Transform,
+    current: Transform,
+}
+
+/// Records the [`Transform`] of each viewable of [`Kind`] `T` into the [`ViewTransformHistory`] of its view.
+///
+/// Viewables which moved farther than the snap distance of [`ViewInterpolation<T>`] are snapped.
+/// Viewables without a [`Transform`] are skipped.
+#[allow(clippy::type_complexity)]
+pub fn record_view_transform<T: Kind>(
+    viewables: Query<(&Transform, &Viewable<T>)>,
+    mut views: Query<Option<&mut ViewTransformHistory>, (With<View<T>>, Without<Viewable<T>>)>,
+    interpolation: Res<ViewInterpolation<T>>,
+    mut commands: Commands,
+) {
+    for (&transform, viewable) in viewables.iter() {
+        let view = viewable.view().entity();
+        let Ok(history) = views.get_mut(view) else {
+            continue;
+        };
+        match history {
+            Some(mut history) => {
+                let distance = history.current.translation.distance(transform.translation);
+                history.previous = if distance > interpolation.snap {
+                    transform
+                } else {
+                    history.current
+                };
+                history.current = transform;
+            }
+            None => {
+                commands.entity(view).insert((
+                    ViewTransformHistory {
+                        previous: transform,
+                        current: transform,
+                    },
+                    transform,
+                ));
+            }
+        }
+    }
+}
+
+/// Sets the [`Transform`] of each view of [`Kind`] `T` by interpolating its [`ViewTransformHistory`]
+/// using the overstep fraction of [`Time<Fixed>`].
+pub fn interpolate_view_transform<T: Kind>(
+    mut views: Query<(&ViewTransformHistory, &mut Transform), With<View<T>>>,
+    time: Res<Time<Fixed>>,
+) {
+    let s = time.overstep_fraction();
+    for (history, mut transform) in views.iter_mut() {
+        let ViewTransformHistory { previous, current } = history;
+        transform.set_if_neq(Transform {
+            translation: previous.translation.lerp(current.translation, s),
+            rotation: previous.rotation.slerp(current.rotation, s),
+            scale: previous.scale.lerp(current.scale, s),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::math::{Quat, Vec3};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::transform::TransformPlugin;
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    fn app() -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins((MinimalPlugins, TransformPlugin));
+        app
+    }
+
+    fn view_translation(app: &ViewTestApp, entity: Entity) -> Vec3 {
+        let view = app.world().view::<Node>(entity).unwrap().entity();
+        app.world().get::<Transform>(view).unwrap().translation
+    }
+
+    #[test]
+    fn offset_is_applied() {
+        let mut app = app();
+        app.add_view_offset_transform::<Node>(Transform::from_xyz(0.0, 2.0, 0.0));
+        let node = app
+            .world_mut()
+            .spawn((Node, Transform::from_xyz(1.0, 0.0, 0.0)))
+            .id();
+        app.update();
+        assert_eq!(view_translation(&app, node), Vec3::new(1.0, 2.0, 0.0));
+
+        app.world_mut()
+            .get_mut::<Transform>(node)
+            .unwrap()
+            .translation
+            .x = 3.0;
+        app.update();
+        assert_eq!(view_translation(&app, node), Vec3::new(3.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn duplicate_offset_is_ignored() {
+        let mut app = app();
+        app.add_view_offset_transform::<Node>(Transform::from_xyz(0.0, 2.0, 0.0))
+            .add_view_synced_transform::<Node>();
+        let node = app.world_mut().spawn((Node, Transform::default())).id();
+        app.update();
+        assert_eq!(view_translation(&app, node), Vec3::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn synced_view_rotates_and_scales() {
+        let mut app = app();
+        app.add_view_synced_transform::<Node>();
+        let node = app.world_mut().spawn((Node, Transform::default())).id();
+        app.update();
+
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Quat::from_rotation_y(1.0))
+            .with_scale(Vec3::splat(2.0));
+        *app.world_mut().get_mut::<Transform>(node).unwrap() = transform;
+        app.update();
+
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert_eq!(*app.world().get::<Transform>(view).unwrap(), transform);
+        assert_eq!(
+            *app.world().get::<GlobalTransform>(view).unwrap(),
+            *app.world().get::<GlobalTransform>(node).unwrap()
+        );
+        assert_eq!(
+            *app.world().get::<GlobalTransform>(view).unwrap(),
+            GlobalTransform::from(transform)
+        );
+    }
+
+    #[test]
+    fn view_global_transform() {
+        let mut app = app();
+        app.add_view_synced_transform::<Node>();
+        let node = app
+            .world_mut()
+            .spawn((Node, Transform::from_xyz(1.0, 2.0, 3.0)))
+            .id();
+        let other = app.world_mut().spawn(Node).id();
+        app.update();
+        let viewable = app.world().get::<Viewable<Node>>(node).unwrap();
+        let global = viewable.view_global_transform(app.world()).unwrap();
+        assert_eq!(global.translation(), Vec3::new(1.0, 2.0, 3.0));
+
+        let viewable = app.world().get::<Viewable<Node>>(other).unwrap();
+        assert!(viewable.view_global_transform(app.world()).is_none());
+    }
+
+    #[test]
+    fn interpolated_view_lerps_and_snaps() {
+        let mut app = app();
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(100)))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                50,
+            )))
+            .add_view_interpolated_transform::<Node>(5.0);
+        let node = app.world_mut().spawn((Node, Transform::default())).id();
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(view_translation(&app, node), Vec3::ZERO);
+
+        let mut observed = Vec::new();
+        app.world_mut()
+            .get_mut::<Transform>(node)
+            .unwrap()
+            .translation
+            .x = 1.0;
+        for _ in 0..4 {
+            app.update();
+            observed.push(view_translation(&app, node).x);
+        }
+        assert_eq!(observed, [0.0, 0.5, 1.0, 1.0]);
+
+        observed.clear();
+        app.world_mut()
+            .get_mut::<Transform>(node)
+            .unwrap()
+            .translation
+            .x = 100.0;
+        for _ in 0..4 {
+            app.update();
+            observed.push(view_translation(&app, node).x);
+        }
+        assert_eq!(observed, [100.0; 4]);
+    }
+}
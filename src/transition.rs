@@ -0,0 +1,230 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::{despawn_view_recursive, View, Viewable, Viewables};
+
+/// A [`Component`] which is inserted on new views of kinds registered using
+/// [`add_view_transitions`](crate::RegisterView::add_view_transitions).
+///
+/// This component is removed once its timer is finished. It is up to the user to animate the view while it appears.
+#[derive(Component, Debug, Clone)]
+pub struct ViewAppearing {
+    /// Timer which tracks the progress of the appearance.
+    pub timer: Timer,
+}
+
+/// A [`Component`] which is inserted on views of kinds registered using
+/// [`add_view_transitions`](crate::RegisterView::add_view_transitions), or with a [`ViewDespawnDelay`],
+/// instead of despawning them.
+///
+/// While disappearing, the view is no longer associated with its viewable entity, which may get a new view.
+/// The view is despawned once its timer is finished. It is up to the user to animate the view while it disappears.
+#[derive(Component, Debug, Clone)]
+pub struct ViewDisappearing {
+    /// Timer which tracks the progress of the disappearance.
+    pub timer: Timer,
+}
+
+/// A [`Component`] which delays the despawn of a view after its viewable entity is despawned.
+///
+/// When inserted on a view, the view is marked as [`ViewDisappearing`] for the given duration instead of being
+/// despawned immediately, like views of kinds registered using [`add_view_transitions`](crate::RegisterView::add_view_transitions).
+/// This overrides the disappear duration of the kind, if any, so each view may have its own delay.
+/// Views without this component are despawned as usual.
+///
+/// Delays are only applied to views which are [linked](crate::RegisterView::add_view_linked) to their viewable.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ViewDespawnDelay(pub Duration);
+
+#[derive(Resource)]
+pub struct ViewTransitions<T: Kind> {
+    appear: Duration,
+    disappear: Duration,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind> ViewTransitions<T> {
+    pub fn new(appear: Duration, disappear: Duration) -> Self {
+        Self {
+            appear,
+            disappear,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub fn insert_view_appearing<T: Kind>(
+    query: Query<&Viewable<T>, Added<Viewable<T>>>,
+    transitions: Res<ViewTransitions<T>>,
+    mut commands: Commands,
+) {
+    for viewable in query.iter() {
+        commands
+            .entity(viewable.view().entity())
+            .insert(ViewAppearing {
+                timer: Timer::new(transitions.appear, TimerMode::Once),
+            });
+    }
+}
+
+/// Marks a view which should be despawned as [`ViewDisappearing`], and unlinks it from its viewable entity.
+///
+/// Returns `false` if the view should be despawned immediately instead.
+pub fn start_view_disappearing<T: Kind>(
+    world: &mut World,
+    viewable: Instance<T>,
+    view: Instance<View<T>>,
+) -> bool {
+    let transitions = world.get_resource::<ViewTransitions<T>>();
+    let disappear = transitions.map(|transitions| transitions.disappear);
+    let Ok(mut view_entity) = world.get_entity_mut(view.entity()) else {
+        return false;
+    };
+    let delay = view_entity.get::<ViewDespawnDelay>().map(|delay| delay.0);
+    let Some(duration) = delay.or(disappear) else {
+        return false;
+    };
+    let timer = Timer::new(duration, TimerMode::Once);
+    view_entity
+        .remove::<ViewAppearing>()
+        .insert(ViewDisappearing { timer });
+    world
+        .resource_mut::<Viewables>()
+        .remove(viewable.entity(), view);
+    true
+}
+
+pub fn tick_view_transitions<T: Kind>(
+    mut appearing: Query<(Entity, &mut ViewAppearing), With<View<T>>>,
+    mut disappearing: Query<(Entity, &mut ViewDisappearing), With<View<T>>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut appearing) in appearing.iter_mut() {
+        if appearing.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<ViewAppearing>();
+        }
+    }
+    for (entity, mut disappearing) in disappearing.iter_mut() {
+        if disappearing.timer.tick(time.delta()).finished() {
+            commands.queue(move |world: &mut World| {
+                despawn_view_recursive(world, entity);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[test]
+    fn despawn_delay_per_view() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        let short = app.world_mut().spawn(Node).id();
+        let long = app.world_mut().spawn(Node).id();
+        let instant = app.world_mut().spawn(Node).id();
+        app.update();
+
+        let short_view = app.world().view::<Node>(short).unwrap().entity();
+        let long_view = app.world().view::<Node>(long).unwrap().entity();
+        let instant_view = app.world().view::<Node>(instant).unwrap().entity();
+        app.world_mut()
+            .entity_mut(short_view)
+            .insert(ViewDespawnDelay(Duration::from_millis(250)));
+        app.world_mut()
+            .entity_mut(long_view)
+            .insert(ViewDespawnDelay(Duration::from_millis(550)));
+        for entity in [short, long, instant] {
+            app.world_mut().despawn(entity);
+        }
+        app.update();
+        assert!(app.world().get_entity(instant_view).is_err());
+        assert!(app.world().get::<ViewDisappearing>(short_view).is_some());
+        assert!(app.world().get::<ViewDisappearing>(long_view).is_some());
+
+        let mut despawned = [None, None];
+        for frame in 1..10 {
+            app.update();
+            for (i, view) in [short_view, long_view].into_iter().enumerate() {
+                if despawned[i].is_none() && app.world().get_entity(view).is_err() {
+                    despawned[i] = Some(frame);
+                }
+            }
+        }
+        assert_eq!(despawned, [Some(2), Some(5)]);
+    }
+
+    fn app(appear: Duration, disappear: Duration) -> ViewTestApp {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_view_transitions::<Node>(appear, disappear);
+        app
+    }
+
+    #[test]
+    fn appearing_until_appear_elapsed() {
+        let mut app = app(Duration::from_millis(250), Duration::ZERO);
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let view = app.world().view::<Node>(node).unwrap().entity();
+        assert!(app.world().get::<ViewAppearing>(view).is_some());
+
+        let mut appeared = None;
+        for frame in 1..10 {
+            app.update();
+            if appeared.is_none() && app.world().get::<ViewAppearing>(view).is_none() {
+                appeared = Some(frame);
+            }
+        }
+        assert_eq!(appeared, Some(3));
+    }
+
+    #[test]
+    fn new_view_while_old_view_disappears() {
+        let mut app = app(Duration::ZERO, Duration::from_millis(250));
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        let old = app.world().view::<Node>(node).unwrap().entity();
+
+        app.world_mut().entity_mut(node).remove::<Node>();
+        app.update();
+        assert!(app.world().get::<ViewDisappearing>(old).is_some());
+        assert!(!app.world().has_view::<Node>(node));
+        assert!(!app
+            .world()
+            .resource::<Viewables>()
+            .views(node)
+            .any(|view| view == old));
+
+        app.world_mut().entity_mut(node).insert(Node);
+        app.update();
+        let new = app.world().view::<Node>(node).unwrap().entity();
+        assert!(new != old);
+        assert!(app.world().get::<ViewDisappearing>(old).is_some());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(old).is_err());
+        assert_eq!(app.world().view::<Node>(node).unwrap().entity(), new);
+    }
+}
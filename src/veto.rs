@@ -0,0 +1,61 @@
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+type Veto<T> = Box<dyn Fn(&World, Instance<T>) -> bool + Send + Sync>;
+
+/// A [`Resource`] which stores the predicates added using [`add_view_veto`](crate::RegisterView::add_view_veto).
+#[derive(Resource)]
+pub struct ViewVetoes<T: Kind> {
+    vetoes: Vec<Veto<T>>,
+}
+
+impl<T: Kind> Default for ViewVetoes<T> {
+    fn default() -> Self {
+        Self { vetoes: Vec::new() }
+    }
+}
+
+impl<T: Kind> ViewVetoes<T> {
+    pub fn add(&mut self, veto: impl Fn(&World, Instance<T>) -> bool + Send + Sync + 'static) {
+        self.vetoes.push(Box::new(veto));
+    }
+
+    /// Returns `true` if any predicate vetoes spawning a view for the given viewable instance.
+    pub fn is_vetoed(&self, world: &World, instance: Instance<T>) -> bool {
+        self.vetoes.iter().any(|veto| veto(world, instance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::{Node, ViewTestApp};
+
+    use super::*;
+
+    #[derive(Resource)]
+    struct Blocked(bool);
+
+    #[test]
+    fn vetoed_viewable_has_no_view_until_lifted() {
+        let mut app = ViewTestApp::with_viewable::<Node>();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Blocked(true))
+            .add_view_veto::<Node>(|world, _| world.resource::<Blocked>().0);
+        let node = app.world_mut().spawn(Node).id();
+        app.update();
+        app.update();
+        assert!(!app.world().has_view::<Node>(node));
+
+        app.world_mut().resource_mut::<Blocked>().0 = false;
+        app.update();
+        assert!(app.world().has_view::<Node>(node));
+
+        app.world_mut().resource_mut::<Blocked>().0 = true;
+        app.update();
+        assert!(app.world().has_view::<Node>(node));
+    }
+}
@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use moonshine_core::prelude::*;
+
+use crate::View;
+
+/// A [`Resource`] which stores the writeback function added using
+/// [`add_view_writeback`](crate::RegisterView::add_view_writeback).
+#[derive(Resource)]
+pub struct ViewWriteback<T: Kind, D: Component, C: Component> {
+    f: Box<dyn Fn(&D) -> C + Send + Sync>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Kind, D: Component, C: Component> ViewWriteback<T, D, C> {
+    pub fn new(f: impl Fn(&D) -> C + Send + Sync + 'static) -> Self {
+        Self {
+            f: Box::new(f),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Writes [`Component`] `C` onto each viewable of [`Kind`] `T` whose view's [`Component`] `D` was changed.
+///
+/// Insertion of `D`, such as when the view is built, is not considered a change.
+/// The viewable's component is only changed if the new value differs from the current one.
+#[allow(clippy::type_complexity)]
+pub fn write_back_views<T: Kind, D: Component, C: Component + PartialEq>(
+    views: Query<(&View<T>, Ref<D>), Changed<D>>,
+    mut viewables: Query<Option<&mut C>, (T::Filter, Without<View<T>>)>,
+    writeback: Res<ViewWriteback<T, D, C>>,
+    mut commands: Commands,
+) {
+    for (view, data) in views.iter() {
+        if data.is_added() {
+            continue;
+        }
+        let entity = view.viewable().entity();
+        let Ok(current) = viewables.get_mut(entity) else {
+            continue;
+        };
+        let value = (writeback.f)(&data);
+        match current {
+            Some(mut current) => {
+                current.set_if_neq(value);
+            }
+            None => {
+                commands.entity(entity).insert(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::MinimalPlugins;
+
+    use crate::prelude::*;
+    use crate::testing::ViewTestApp;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct Bird;
+
+    #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    struct Position(f32);
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Height(f32);
+
+    impl BuildView for Bird {
+        fn build(_: &World, _: Object<Self>, mut view: ViewCommands<Self>) {
+            view.insert(Position(0.0));
+        }
+    }
+
+    #[test]
+    fn write_back_same_component() {
+        let mut app = ViewTestApp::with_viewable::<Bird>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_writeback::<Bird, Position, Position>(|position| *position);
+
+        let bird = app.world_mut().spawn((Bird, Position(0.0))).id();
+        app.update();
+        let view = app.world().view::<Bird>(bird).unwrap().entity();
+
+        app.world_mut().get_mut::<Position>(view).unwrap().0 = 5.0;
+        app.update();
+        assert_eq!(app.world().get::<Position>(bird), Some(&Position(5.0)));
+        assert_eq!(app.world().get::<Position>(view), Some(&Position(5.0)));
+    }
+
+    #[test]
+    fn write_back_inserts_missing_component() {
+        let mut app = ViewTestApp::with_viewable::<Bird>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_writeback::<Bird, Position, Height>(|position| Height(position.0));
+
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        assert!(app.world().get::<Height>(bird).is_none());
+
+        let view = app.world().view::<Bird>(bird).unwrap().entity();
+        app.world_mut().get_mut::<Position>(view).unwrap().0 = 2.0;
+        app.update();
+        assert_eq!(app.world().get::<Height>(bird), Some(&Height(2.0)));
+    }
+
+    #[test]
+    fn duplicate_writeback_is_ignored() {
+        let mut app = ViewTestApp::with_viewable::<Bird>();
+        app.add_plugins(MinimalPlugins)
+            .add_view_writeback::<Bird, Position, Height>(|position| Height(position.0))
+            .add_view_writeback::<Bird, Position, Height>(|position| Height(-position.0));
+
+        let bird = app.world_mut().spawn(Bird).id();
+        app.update();
+        let view = app.world().view::<Bird>(bird).unwrap().entity();
+        app.world_mut().get_mut::<Position>(view).unwrap().0 = 2.0;
+        app.update();
+        assert_eq!(app.world().get::<Height>(bird), Some(&Height(2.0)));
+    }
+}